@@ -0,0 +1,164 @@
+// Appending N elements to a singly linked list, with and without a cached tail.
+//
+// `insert_at_tail` walks from the head to the last node on every call, so the i-th
+// append costs i steps and N appends cost about N^2 / 2 steps in total. A list that
+// remembers its tail can append in one step, so N appends cost N steps.
+//
+// Plain std timing, no benchmark harness: build with optimizations and run it,
+//   rustc --edition 2021 -O benches/linked_list.rs -o /tmp/linked_list && /tmp/linked_list
+// Each case is run ten times and the fastest run is kept, since slower runs only
+// add noise from the rest of the machine.
+//
+// One run on a Linux x86-64 machine, rustc -O:
+//
+//      N   walk from head     tail pointer  ratio
+//   1000           954 us            18 us    51x
+//   2000          3646 us            33 us   107x
+//   4000         14163 us            75 us   186x
+//   8000         60468 us           151 us   399x
+//  16000        242706 us           265 us   913x
+//
+// Reading the results:
+// - "walk from head" roughly quadruples every time N doubles (954 -> 3646 -> 14163
+//   -> 60468 us), which is the quadratic blowup. Going from 1,000 to 8,000 elements
+//   made it 63x slower, close to the 64x that N^2 predicts.
+// - "tail pointer" roughly doubles every time N doubles, so it's linear.
+// - So the ratio between them keeps growing with N: about 50x at 1,000 elements and
+//   several hundred times at 16,000 (it moves the most between runs, since the
+//   tail-pointer times are so short). The walk does no more allocating than the
+//   tail pointer does, the extra time is all spent following `next` pointers to get
+//   back to the end.
+// The exact numbers depend on the machine, the shape of the curves shouldn't.
+//
+// This is the motivation for keeping a tail pointer (see docs/linked-list/singly/tail.md).
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+struct Node {
+    data: i32,
+    next: Option<Box<Node>>,
+}
+
+// Same as Node::insert_at_tail in docs/linked-list/code/singly.rs
+fn insert_at_tail(head: Option<Box<Node>>, data: i32) -> Option<Box<Node>> {
+    match head {
+        None => Some(Box::new(Node { data, next: None })),
+        Some(mut node) => {
+            let mut current = &mut node;
+            while current.next.is_some() {
+                current = current.next.as_mut().unwrap();
+            }
+            current.next = Some(Box::new(Node { data, next: None }));
+
+            Some(node)
+        }
+    }
+}
+
+struct TailList {
+    head: Option<Box<Node>>,
+    tail: *mut Node,
+}
+
+impl TailList {
+    fn new() -> Self {
+        TailList {
+            head: None,
+            tail: std::ptr::null_mut(),
+        }
+    }
+
+    fn push_back(&mut self, data: i32) {
+        let new_node = Box::new(Node { data, next: None });
+
+        let slot = if self.tail.is_null() {
+            &mut self.head
+        } else {
+            // SAFETY: tail is non-null only while it points at the last node owned by head,
+            // and nothing else holds a reference into the list while we append
+            unsafe { &mut (*self.tail).next }
+        };
+
+        self.tail = &mut **slot.insert(new_node);
+    }
+}
+
+// Both lists are dropped iteratively, since the default drop recurses once per node
+fn drop_list(head: Option<Box<Node>>) {
+    let mut current = head;
+
+    while let Some(mut node) = current {
+        current = node.next.take();
+    }
+}
+
+fn sum(head: &Option<Box<Node>>) -> i64 {
+    let mut total = 0;
+    let mut current = head;
+
+    while let Some(node) = current {
+        total += node.data as i64;
+        current = &node.next;
+    }
+
+    total
+}
+
+fn walk_from_head(n: i32) -> i64 {
+    let mut head = None;
+    for i in 0..n {
+        head = insert_at_tail(head, black_box(i));
+    }
+
+    let total = sum(&head);
+    drop_list(head);
+    total
+}
+
+fn tail_pointer(n: i32) -> i64 {
+    let mut list = TailList::new();
+    for i in 0..n {
+        list.push_back(black_box(i));
+    }
+
+    let total = sum(&list.head);
+    drop_list(list.head.take());
+    total
+}
+
+// The fastest of ten runs
+fn time(append: fn(i32) -> i64, n: i32) -> Duration {
+    (0..10)
+        .map(|_| {
+            let start = Instant::now();
+            let total = black_box(append(n));
+            let elapsed = start.elapsed();
+
+            // Both build 0, 1, ..., n - 1
+            assert_eq!(total, (n as i64) * (n as i64 - 1) / 2);
+            elapsed
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    println!(
+        "{:>6} {:>16} {:>16} {:>6}",
+        "N", "walk from head", "tail pointer", "ratio"
+    );
+
+    for n in [1_000, 2_000, 4_000, 8_000, 16_000] {
+        let walk = time(walk_from_head, n);
+        let tail = time(tail_pointer, n);
+
+        println!(
+            "{:>6} {:>13} us {:>13} us {:>5.0}x",
+            n,
+            walk.as_micros(),
+            tail.as_micros(),
+            walk.as_secs_f64() / tail.as_secs_f64()
+        );
+    }
+}
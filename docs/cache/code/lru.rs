@@ -0,0 +1,215 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::{Rc, Weak};
+
+type NodeRef<K, V> = Rc<RefCell<Node<K, V>>>;
+
+// Every node keeps its key so that evicting the tail can also remove it from the map
+#[allow(dead_code)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    next: Option<NodeRef<K, V>>,
+    prev: Option<Weak<RefCell<Node<K, V>>>>,
+}
+
+// The map finds a node in O(1), the list keeps nodes in usage order in O(1).
+// Most recently used is at the head, least recently used at the tail.
+#[allow(dead_code)]
+struct LruCache<K, V> {
+    capacity: usize,
+    map: HashMap<K, NodeRef<K, V>>,
+    head: Option<NodeRef<K, V>>,
+    tail: Option<NodeRef<K, V>>,
+}
+
+#[allow(dead_code)]
+impl<K: Hash + Eq + Clone, V: Clone> LruCache<K, V> {
+    fn new(capacity: usize) -> Self {
+        // With no room at all, put would have nothing to evict to make space
+        assert!(capacity >= 1, "an LRU cache needs a capacity of at least 1");
+
+        LruCache {
+            capacity,
+            map: HashMap::new(),
+            head: None,
+            tail: None,
+        }
+    }
+
+    // Unlinks a node from wherever it is in the list, leaving it in the map
+    fn detach(&mut self, node: &NodeRef<K, V>) {
+        let prev = node
+            .borrow_mut()
+            .prev
+            .take()
+            .and_then(|prev| prev.upgrade());
+        let next = node.borrow_mut().next.take();
+
+        match &next {
+            Some(next) => next.borrow_mut().prev = prev.as_ref().map(Rc::downgrade),
+            None => self.tail = prev.clone(),
+        }
+
+        match prev {
+            Some(prev) => prev.borrow_mut().next = next,
+            None => self.head = next,
+        }
+    }
+
+    fn push_front(&mut self, node: NodeRef<K, V>) {
+        match self.head.take() {
+            None => {
+                self.tail = Some(node.clone());
+            }
+            Some(old_head) => {
+                old_head.borrow_mut().prev = Some(Rc::downgrade(&node));
+                node.borrow_mut().next = Some(old_head);
+            }
+        }
+
+        self.head = Some(node);
+    }
+
+    // A hit counts as a use, so the entry moves to the front
+    fn get(&mut self, key: &K) -> Option<V> {
+        let node = self.map.get(key)?.clone();
+
+        self.detach(&node);
+        self.push_front(node.clone());
+
+        let value = node.borrow().value.clone();
+        Some(value)
+    }
+
+    // Returns the key that was evicted to make room, if any
+    fn put(&mut self, key: K, value: V) -> Option<K> {
+        if let Some(node) = self.map.get(&key).cloned() {
+            node.borrow_mut().value = value;
+
+            self.detach(&node);
+            self.push_front(node);
+            return None;
+        }
+
+        let mut evicted = None;
+
+        if self.map.len() == self.capacity {
+            if let Some(lru) = self.tail.clone() {
+                self.detach(&lru);
+
+                let lru_key = lru.borrow().key.clone();
+                self.map.remove(&lru_key);
+                evicted = Some(lru_key);
+            }
+        }
+
+        let node = Rc::new(RefCell::new(Node {
+            key: key.clone(),
+            value,
+            next: None,
+            prev: None,
+        }));
+
+        self.map.insert(key, node.clone());
+        self.push_front(node);
+
+        evicted
+    }
+
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    // Keys from most to least recently used
+    fn keys(&self) -> Vec<K> {
+        let mut keys = Vec::new();
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            keys.push(node.borrow().key.clone());
+            current = node.borrow().next.clone();
+        }
+
+        keys
+    }
+}
+
+fn main() {
+    let mut cache = LruCache::new(2);
+
+    println!("{:?}", cache.put(1, "one")); // None, [1]
+    println!("{:?}", cache.put(2, "two")); // None, [2, 1]
+    println!("{:?}", cache.get(&1)); // Some("one"), [1, 2]
+    println!("{:?}", cache.put(3, "three")); // Some(2), [3, 1]
+    println!("{:?}", cache.get(&2)); // None
+    println!("{:?}", cache.put(4, "four")); // Some(1), [4, 3]
+    println!("{:?}", cache.get(&1)); // None
+    println!("{:?}", cache.get(&3)); // Some("three"), [3, 4]
+    println!("{:?}", cache.get(&4)); // Some("four"), [4, 3]
+    println!("{:?}", cache.keys()); // [4, 3]
+
+    // Updating an existing key counts as a use but never evicts
+    println!("{:?}", cache.put(3, "THREE")); // None, [3, 4]
+    println!("{:?}", cache.put(5, "five")); // Some(4), [5, 3]
+    println!("{:?}", cache.get(&3)); // Some("THREE"), [3, 5]
+    println!("{:?}", cache.keys()); // [3, 5]
+    println!("Length: {}", cache.len()); // Length: 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_the_least_recently_used_key() {
+        let mut cache = LruCache::new(2);
+
+        assert_eq!(cache.put(1, "one"), None);
+        assert_eq!(cache.put(2, "two"), None);
+        assert_eq!(cache.get(&1), Some("one")); // 2 is now the oldest
+        assert_eq!(cache.put(3, "three"), Some(2));
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.keys(), vec![3, 1]);
+
+        assert_eq!(cache.put(4, "four"), Some(1)); // 3 was used more recently
+        assert_eq!(cache.keys(), vec![4, 3]);
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&3), Some("three"));
+        assert_eq!(cache.keys(), vec![3, 4]);
+        assert_eq!(cache.get(&4), Some("four"));
+        assert_eq!(cache.keys(), vec![4, 3]);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn updating_a_key_moves_it_to_the_front_without_evicting() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, 10);
+        cache.put(2, 20);
+
+        assert_eq!(cache.put(1, 11), None);
+        assert_eq!(cache.keys(), vec![1, 2]);
+        assert_eq!(cache.put(3, 30), Some(2));
+        assert_eq!(cache.get(&1), Some(11));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn a_single_slot_cache_keeps_only_the_latest_key() {
+        let mut cache = LruCache::new(1);
+
+        assert_eq!(cache.put('a', 1), None);
+        assert_eq!(cache.put('b', 2), Some('a'));
+        assert_eq!(cache.get(&'a'), None);
+        assert_eq!(cache.get(&'b'), Some(2));
+        assert_eq!(cache.keys(), vec!['b']);
+    }
+
+    #[test]
+    #[should_panic(expected = "an LRU cache needs a capacity of at least 1")]
+    fn zero_capacity_is_rejected() {
+        LruCache::<i32, i32>::new(0);
+    }
+}
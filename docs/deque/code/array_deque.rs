@@ -0,0 +1,240 @@
+// `head` is the index of the front element, and the elements run from there for
+// `len` slots, wrapping past the end of `buf` back to index 0. The back element sits
+// at (head + len - 1) % capacity. Unlike the ring buffer queue, this one grows when full.
+#[allow(dead_code)]
+struct ArrayDeque {
+    buf: Vec<Option<i32>>,
+    head: usize,
+    len: usize,
+}
+
+#[allow(dead_code)]
+impl ArrayDeque {
+    fn new(capacity: usize) -> Self {
+        ArrayDeque {
+            buf: vec![None; capacity.max(1)],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    // Physical index of the i-th element from the front
+    fn index(&self, i: usize) -> usize {
+        (self.head + i) % self.capacity()
+    }
+
+    fn push_back(&mut self, data: i32) {
+        if self.len == self.capacity() {
+            self.grow();
+        }
+
+        let i = self.index(self.len);
+        self.buf[i] = Some(data);
+        self.len += 1;
+    }
+
+    fn push_front(&mut self, data: i32) {
+        if self.len == self.capacity() {
+            self.grow();
+        }
+
+        // Step head back one slot. Adding capacity first keeps 0 - 1 from underflowing,
+        // so from index 0 it wraps to the last slot.
+        self.head = (self.head + self.capacity() - 1) % self.capacity();
+        self.buf[self.head] = Some(data);
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) -> Option<i32> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let data = self.buf[self.head].take();
+        self.head = self.index(1);
+        self.len -= 1;
+
+        data
+    }
+
+    fn pop_back(&mut self) -> Option<i32> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let i = self.index(self.len - 1);
+        self.len -= 1;
+
+        self.buf[i].take()
+    }
+
+    fn front(&self) -> Option<i32> {
+        if self.len == 0 {
+            None
+        } else {
+            self.buf[self.head]
+        }
+    }
+
+    fn back(&self) -> Option<i32> {
+        if self.len == 0 {
+            None
+        } else {
+            self.buf[self.index(self.len - 1)]
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // The elements may wrap around the end of the old buffer, so copying it as-is
+    // would leave a gap in the middle. Copy them out in order to the start instead.
+    fn grow(&mut self) {
+        let mut bigger = vec![None; self.capacity() * 2];
+
+        for (i, slot) in bigger.iter_mut().enumerate().take(self.len) {
+            let old = self.index(i);
+            *slot = self.buf[old].take();
+        }
+
+        self.buf = bigger;
+        self.head = 0;
+    }
+
+    // Front to back
+    fn to_vec(&self) -> Vec<i32> {
+        (0..self.len)
+            .filter_map(|i| self.buf[self.index(i)])
+            .collect()
+    }
+}
+
+fn main() {
+    let mut deque = ArrayDeque::new(4);
+
+    deque.push_back(1); // [1, _, _, _] head = 0
+    deque.push_back(2); // [1, 2, _, _] head = 0
+    deque.push_front(0); // [1, 2, _, 0] head = 3, wrapped to the end
+    deque.push_front(-1); // [1, 2, -1, 0] head = 2
+    println!("{:?}", deque.to_vec()); // [-1, 0, 1, 2]
+    println!("Capacity: {}", deque.capacity()); // Capacity: 4
+
+    println!("{:?}", deque.pop_back()); // Some(2)
+    println!("{:?}", deque.pop_front()); // Some(-1)
+    deque.push_back(3); // [1, 3, _, 0] head = 3
+    deque.push_back(4); // [1, 3, 4, 0] head = 3, full again
+    println!("{:?}", deque.to_vec()); // [0, 1, 3, 4]
+    println!("{:?} {:?}", deque.front(), deque.back()); // Some(0) Some(4)
+
+    // Full with the elements wrapped, so growing has to unwrap them
+    deque.push_front(-2); // [0, 1, 3, 4, _, _, _, -2] head = 7
+    println!("{:?}", deque.to_vec()); // [-2, 0, 1, 3, 4]
+    println!("Capacity: {}", deque.capacity()); // Capacity: 8
+
+    // Alternate ends until empty
+    let mut drained = Vec::new();
+    while !deque.is_empty() {
+        drained.extend(deque.pop_front());
+        drained.extend(deque.pop_back());
+    }
+    println!("{:?}", drained); // [-2, 4, 0, 3, 1]
+    println!("{:?} {:?}", deque.pop_front(), deque.pop_back()); // None None
+
+    // Keep pushing on one end and popping from the other, so head laps the buffer many times
+    let mut lapping = ArrayDeque::new(3);
+    let mut in_order = true;
+    for i in 0..100 {
+        lapping.push_front(i);
+        if i >= 2 {
+            in_order &= lapping.pop_back() == Some(i - 2);
+        }
+    }
+    assert!(in_order);
+    println!("Capacity: {}", lapping.capacity()); // Capacity: 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::VecDeque;
+
+    #[test]
+    fn both_ends_wrap_around_the_buffer() {
+        let mut deque = ArrayDeque::new(4);
+
+        deque.push_front(1); // Wraps straight to the last slot
+        assert_eq!(deque.head, 3);
+        deque.push_back(2); // Wraps to index 0
+        deque.push_front(0);
+        deque.push_back(3);
+
+        assert_eq!(deque.capacity(), 4);
+        assert_eq!(deque.to_vec(), vec![0, 1, 2, 3]);
+        assert_eq!((deque.front(), deque.back()), (Some(0), Some(3)));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(0));
+        assert_eq!(deque.to_vec(), vec![1, 2]);
+    }
+
+    #[test]
+    fn growing_a_wrapped_deque_keeps_the_order() {
+        let mut deque = ArrayDeque::new(2);
+        for i in 0..3 {
+            deque.push_front(-i);
+            deque.push_back(i + 1);
+        }
+
+        assert_eq!(deque.to_vec(), vec![-2, -1, 0, 1, 2, 3]);
+        assert_eq!(deque.capacity(), 8);
+    }
+
+    #[test]
+    fn matches_vec_deque_through_interleaved_operations() {
+        let mut deque = ArrayDeque::new(3);
+        let mut expected = VecDeque::new();
+
+        for i in 0..1000 {
+            match i * 7 % 5 {
+                0 => {
+                    deque.push_front(i);
+                    expected.push_front(i);
+                }
+                1 | 2 => {
+                    deque.push_back(i);
+                    expected.push_back(i);
+                }
+                3 => assert_eq!(deque.pop_front(), expected.pop_front()),
+                _ => assert_eq!(deque.pop_back(), expected.pop_back()),
+            }
+
+            assert_eq!(deque.len(), expected.len());
+            assert_eq!(deque.front(), expected.front().copied());
+            assert_eq!(deque.back(), expected.back().copied());
+        }
+
+        assert_eq!(deque.to_vec(), Vec::from(expected));
+    }
+
+    #[test]
+    fn empty_deque_has_no_ends() {
+        let mut deque = ArrayDeque::new(0);
+
+        assert!(deque.is_empty());
+        assert_eq!((deque.front(), deque.back()), (None, None));
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+
+        deque.push_back(1);
+        assert_eq!(deque.to_vec(), vec![1]);
+    }
+}
@@ -0,0 +1,163 @@
+use std::cmp::{max, min};
+
+// table[i][j] is the LCS length of the first i chars of a and the first j chars of b.
+// Row 0 and column 0 compare against an empty prefix, so they stay 0.
+fn longest_common_subsequence(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            table[i][j] = if a[i - 1] == b[j - 1] {
+                // Both last chars match, so they extend the LCS of what's before them
+                table[i - 1][j - 1] + 1
+            } else {
+                // Otherwise one of them isn't part of it: drop whichever helps more
+                max(table[i - 1][j], table[i][j - 1])
+            };
+        }
+    }
+
+    table[a.len()][b.len()]
+}
+
+// Minimum inserts, deletes and substitutions to turn a into b (Levenshtein distance).
+// table[i][j] is the distance between the first i chars of a and the first j of b.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    // Getting to or from an empty string takes one edit per char
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            table[i][j] = if a[i - 1] == b[j - 1] {
+                table[i - 1][j - 1]
+            } else {
+                1 + min(
+                    table[i - 1][j - 1], // Substitute a[i - 1] with b[j - 1]
+                    min(
+                        table[i - 1][j], // Delete a[i - 1]
+                        table[i][j - 1], // Insert b[j - 1]
+                    ),
+                )
+            };
+        }
+    }
+
+    table[a.len()][b.len()]
+}
+
+// Best total value from items that fit in cap, each item used at most once.
+// table[i][w] is the best value using only the first i items with capacity w.
+fn knapsack_01(weights: &[u32], values: &[u32], cap: u32) -> u32 {
+    let n = weights.len();
+    let cap = cap as usize;
+    let mut table = vec![vec![0; cap + 1]; n + 1];
+
+    for i in 1..=n {
+        let weight = weights[i - 1] as usize;
+        let value = values[i - 1];
+
+        for w in 0..=cap {
+            // Skip item i - 1...
+            table[i][w] = table[i - 1][w];
+
+            // ...or take it, if it fits, on top of the best for the remaining capacity
+            if weight <= w {
+                table[i][w] = max(table[i][w], table[i - 1][w - weight] + value);
+            }
+        }
+    }
+
+    table[n][cap]
+}
+
+fn main() {
+    println!("{}", longest_common_subsequence("ABCBDAB", "BDCABA")); // 4, e.g. "BCBA"
+    println!("{}", longest_common_subsequence("abc", "abc")); // 3
+    println!("{}", longest_common_subsequence("abc", "def")); // 0
+    println!("{}", longest_common_subsequence("", "abc")); // 0
+
+    println!("{}", edit_distance("kitten", "sitting")); // 3
+    println!("{}", edit_distance("flaw", "lawn")); // 2
+    println!("{}", edit_distance("same", "same")); // 0
+    println!("{}", edit_distance("", "abc")); // 3
+    println!("{}", edit_distance("abc", "")); // 3
+
+    let weights = [1, 3, 4, 5];
+    let values = [1, 4, 5, 7];
+    println!("{}", knapsack_01(&weights, &values, 7)); // 9, the items weighing 3 and 4
+    println!("{}", knapsack_01(&weights, &values, 0)); // 0
+    println!("{}", knapsack_01(&[10], &[100], 5)); // 0, nothing fits
+    println!("{}", knapsack_01(&[], &[], 10)); // 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_common_subsequence_lengths() {
+        assert_eq!(longest_common_subsequence("ABCBDAB", "BDCABA"), 4);
+        assert_eq!(longest_common_subsequence("abc", "abc"), 3);
+        assert_eq!(longest_common_subsequence("abc", "def"), 0);
+        assert_eq!(longest_common_subsequence("", "abc"), 0);
+        assert_eq!(longest_common_subsequence("abc", ""), 0);
+        assert_eq!(longest_common_subsequence("aggtab", "gxtxayb"), 4);
+    }
+
+    #[test]
+    fn longest_common_subsequence_is_symmetric() {
+        let pairs = [("ABCBDAB", "BDCABA"), ("kitten", "sitting"), ("", "x")];
+        for (a, b) in pairs {
+            assert_eq!(
+                longest_common_subsequence(a, b),
+                longest_common_subsequence(b, a)
+            );
+        }
+    }
+
+    #[test]
+    fn edit_distance_counts_edits() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("flaw", "lawn"), 2);
+        assert_eq!(edit_distance("same", "same"), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", "abd"), 1);
+    }
+
+    #[test]
+    fn edit_distance_works_on_chars_not_bytes() {
+        assert_eq!(edit_distance("café", "cafe"), 1);
+        assert_eq!(longest_common_subsequence("naïve", "naive"), 4);
+    }
+
+    #[test]
+    fn knapsack_picks_the_best_subset() {
+        let weights = [1, 3, 4, 5];
+        let values = [1, 4, 5, 7];
+        assert_eq!(knapsack_01(&weights, &values, 7), 9);
+        assert_eq!(knapsack_01(&weights, &values, 0), 0);
+        assert_eq!(knapsack_01(&weights, &values, 13), 17);
+        assert_eq!(knapsack_01(&[10], &[100], 5), 0);
+        assert_eq!(knapsack_01(&[10], &[100], 10), 100);
+        assert_eq!(knapsack_01(&[], &[], 10), 0);
+    }
+
+    #[test]
+    fn knapsack_uses_each_item_at_most_once() {
+        // Taking the one item twice would be worth 20
+        assert_eq!(knapsack_01(&[2], &[10], 4), 10);
+    }
+}
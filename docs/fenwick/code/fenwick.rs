@@ -0,0 +1,129 @@
+// tree is 1-indexed: tree[i] holds the sum of the `lowbit(i)` elements ending at
+// position i, where lowbit(i) is the value of i's lowest set bit. For example
+// tree[12] (0b1100) covers positions 9..=12 and tree[8] (0b1000) covers 1..=8.
+#[allow(dead_code)]
+struct FenwickTree {
+    tree: Vec<i64>,
+}
+
+// i & -i keeps only the lowest set bit: 12 & -12 == 4. usize has no negation,
+// but wrapping_neg gives the same two's complement bits.
+fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+#[allow(dead_code)]
+impl FenwickTree {
+    fn new(n: usize) -> Self {
+        FenwickTree {
+            tree: vec![0; n + 1],
+        }
+    }
+
+    fn from_slice(data: &[i64]) -> Self {
+        let mut fenwick = FenwickTree::new(data.len());
+
+        for (i, &x) in data.iter().enumerate() {
+            fenwick.update(i, x);
+        }
+
+        fenwick
+    }
+
+    // Adds delta to element i (0-based). Adding the low bit climbs to the next
+    // range that also covers i, until past the end: O(log n).
+    fn update(&mut self, i: usize, delta: i64) {
+        let mut i = i + 1;
+
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += lowbit(i);
+        }
+    }
+
+    // Sum of the first i elements. Removing the low bit jumps to the range ending
+    // just before the current one: O(log n).
+    fn prefix_sum(&self, mut i: usize) -> i64 {
+        let mut sum = 0;
+
+        while i > 0 {
+            sum += self.tree[i];
+            i -= lowbit(i);
+        }
+
+        sum
+    }
+
+    // Sum of the half-open range [l, r)
+    fn range_sum(&self, l: usize, r: usize) -> i64 {
+        self.prefix_sum(r) - self.prefix_sum(l)
+    }
+}
+
+fn main() {
+    //          0  1  2  3  4   5  6  7
+    let data = [5, 3, 7, 9, 6, -4, 1, 2];
+    let mut fenwick = FenwickTree::from_slice(&data);
+
+    println!("{}", fenwick.prefix_sum(4)); // 24
+    println!("{}", fenwick.range_sum(2, 5)); // 22
+    println!("{}", fenwick.range_sum(0, 8)); // 29
+
+    let mut naive = data.to_vec();
+    let updates = [(2, 3), (0, -5), (7, 10), (5, 4), (2, -1)];
+    for (i, delta) in updates {
+        fenwick.update(i, delta);
+        naive[i] += delta;
+    }
+
+    // Compare every prefix against a plain running total
+    let mut running = 0;
+    let mut all_match = fenwick.prefix_sum(0) == 0;
+    for (i, x) in naive.iter().enumerate() {
+        running += x;
+        all_match &= fenwick.prefix_sum(i + 1) == running;
+    }
+    assert!(all_match);
+    println!("{}", fenwick.range_sum(2, 5)); // 24
+    println!("{:?}", naive); // [0, 3, 9, 9, 6, 0, 1, 12]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowbit_keeps_the_lowest_set_bit() {
+        assert_eq!(lowbit(12), 4);
+        assert_eq!(lowbit(8), 8);
+        assert_eq!(lowbit(7), 1);
+        assert_eq!(lowbit(0), 0);
+    }
+
+    #[test]
+    fn range_sums_match_a_plain_array_through_updates() {
+        let mut data: Vec<i64> = vec![5, 3, 7, 9, 6, -4, 1, 2, 8, -3, 0];
+        let mut fenwick = FenwickTree::from_slice(&data);
+
+        for step in 0..30 {
+            let i = step * 7 % data.len();
+            let delta = step as i64 - 15;
+            fenwick.update(i, delta);
+            data[i] += delta;
+
+            for l in 0..=data.len() {
+                for r in l..=data.len() {
+                    let expected: i64 = data[l..r].iter().sum();
+                    assert_eq!(fenwick.range_sum(l, r), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn new_tree_is_all_zeros() {
+        let fenwick = FenwickTree::new(5);
+        assert_eq!(fenwick.prefix_sum(5), 0);
+        assert_eq!(FenwickTree::from_slice(&[]).prefix_sum(0), 0);
+    }
+}
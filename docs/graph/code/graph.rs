@@ -0,0 +1,1126 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+#[derive(Debug)]
+struct CycleError;
+
+#[derive(Debug, PartialEq)]
+struct NegativeCycleError;
+
+// White: not visited yet. Gray: on the current DFS path. Black: fully explored.
+#[derive(Clone, Copy, PartialEq)]
+enum Color {
+    White,
+    Gray,
+    Black,
+}
+
+// Adjacency list: adj[u] holds (v, weight) for every edge u -> v.
+// Unweighted edges are stored with weight 1.
+#[allow(dead_code)]
+struct Graph {
+    adj: Vec<Vec<(usize, u32)>>,
+}
+
+#[allow(dead_code)]
+impl Graph {
+    fn new(n: usize) -> Self {
+        Graph {
+            adj: vec![Vec::new(); n],
+        }
+    }
+
+    // Directed edge u -> v
+    fn add_edge(&mut self, u: usize, v: usize) {
+        self.add_weighted_edge(u, v, 1);
+    }
+
+    fn add_weighted_edge(&mut self, u: usize, v: usize, weight: u32) {
+        self.adj[u].push((v, weight));
+    }
+
+    fn add_undirected_edge(&mut self, u: usize, v: usize) {
+        self.add_edge(u, v);
+        self.add_edge(v, u);
+    }
+
+    // Same message as prim and bellman_ford, for every search that starts at a vertex
+    fn assert_vertex(&self, caller: &str, name: &str, v: usize) {
+        assert!(
+            v < self.adj.len(),
+            "{}: {} is {} but the graph has {} vertices",
+            caller,
+            name,
+            v,
+            self.adj.len()
+        );
+    }
+
+    fn bfs(&self, start: usize) -> Vec<usize> {
+        self.assert_vertex("bfs", "start", start);
+
+        let mut order = Vec::new();
+        let mut visited = vec![false; self.adj.len()];
+        let mut queue = VecDeque::new();
+
+        visited[start] = true;
+        queue.push_back(start);
+
+        while let Some(u) = queue.pop_front() {
+            order.push(u);
+
+            for &(v, _) in &self.adj[u] {
+                if !visited[v] {
+                    visited[v] = true;
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        order
+    }
+
+    fn dfs(&self, start: usize) -> Vec<usize> {
+        self.assert_vertex("dfs", "start", start);
+
+        let mut order = Vec::new();
+        let mut visited = vec![false; self.adj.len()];
+
+        self.dfs_visit(start, &mut visited, &mut order);
+
+        order
+    }
+
+    fn dfs_visit(&self, u: usize, visited: &mut Vec<bool>, order: &mut Vec<usize>) {
+        visited[u] = true;
+        order.push(u);
+
+        for &(v, _) in &self.adj[u] {
+            if !visited[v] {
+                self.dfs_visit(v, visited, order);
+            }
+        }
+    }
+
+    fn dfs_iterative(&self, start: usize) -> Vec<usize> {
+        self.assert_vertex("dfs_iterative", "start", start);
+
+        let mut order = Vec::new();
+        let mut visited = vec![false; self.adj.len()];
+        let mut stack = vec![start];
+
+        while let Some(u) = stack.pop() {
+            if visited[u] {
+                continue;
+            }
+
+            visited[u] = true;
+            order.push(u);
+
+            // Push in reverse so the first neighbor is popped first, matching the recursive order
+            for &(v, _) in self.adj[u].iter().rev() {
+                if !visited[v] {
+                    stack.push(v);
+                }
+            }
+        }
+
+        order
+    }
+
+    // Labels each vertex with the component it's in, numbered in order of their
+    // lowest vertex. Flood fills from every vertex that hasn't been labelled yet.
+    // Assumes the graph is undirected, i.e. every edge is stored both ways.
+    fn component_ids(&self) -> Vec<usize> {
+        let mut ids: Vec<Option<usize>> = vec![None; self.adj.len()];
+        let mut next_id = 0;
+
+        for start in 0..self.adj.len() {
+            if ids[start].is_some() {
+                continue;
+            }
+
+            let mut queue = VecDeque::from([start]);
+            ids[start] = Some(next_id);
+
+            while let Some(u) = queue.pop_front() {
+                for &(v, _) in &self.adj[u] {
+                    if ids[v].is_none() {
+                        ids[v] = Some(next_id);
+                        queue.push_back(v);
+                    }
+                }
+            }
+
+            next_id += 1;
+        }
+
+        ids.into_iter().flatten().collect()
+    }
+
+    fn connected_components(&self) -> usize {
+        self.component_ids()
+            .into_iter()
+            .max()
+            .map_or(0, |last| last + 1)
+    }
+
+    // Two-colors each component with BFS: a vertex's neighbors get the opposite color.
+    // An edge between two vertices of the same color means an odd cycle, which can't be
+    // two-colored. Like component_ids, expects every edge to be stored both ways.
+    fn is_bipartite(&self) -> bool {
+        let mut side: Vec<Option<bool>> = vec![None; self.adj.len()];
+
+        for start in 0..self.adj.len() {
+            if side[start].is_some() {
+                continue;
+            }
+
+            let mut queue = VecDeque::from([start]);
+            side[start] = Some(false);
+
+            while let Some(u) = queue.pop_front() {
+                let color = side[u].unwrap(); // Colored before it was queued
+
+                for &(v, _) in &self.adj[u] {
+                    match side[v] {
+                        None => {
+                            side[v] = Some(!color);
+                            queue.push_back(v);
+                        }
+                        Some(other) if other == color => return false,
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    // Shortest distance from `source` to every vertex, None if it can't be reached.
+    // Weights are u32, so there are no negative edges for Dijkstra to trip over.
+    fn dijkstra(&self, source: usize) -> Vec<Option<u32>> {
+        self.assert_vertex("dijkstra", "source", source);
+
+        let mut dist: Vec<Option<u32>> = vec![None; self.adj.len()];
+        // Reverse turns the max-heap into a min-heap ordered by distance
+        let mut heap = BinaryHeap::new();
+
+        dist[source] = Some(0);
+        heap.push(Reverse((0, source)));
+
+        while let Some(Reverse((d, u))) = heap.pop() {
+            // A shorter path to u was already processed, this entry is stale
+            if dist[u].is_some_and(|best| d > best) {
+                continue;
+            }
+
+            for &(v, weight) in &self.adj[u] {
+                // A path too long to fit in a u32 can't be the shortest one anyway
+                let Some(candidate) = d.checked_add(weight) else {
+                    continue;
+                };
+
+                if dist[v].is_none_or(|best| candidate < best) {
+                    dist[v] = Some(candidate);
+                    heap.push(Reverse((candidate, v)));
+                }
+            }
+        }
+
+        dist
+    }
+
+    // Kahn's algorithm: repeatedly take a vertex with no incoming edges left.
+    // If some vertices never reach in-degree 0, they're stuck in a cycle.
+    fn topological_sort(&self) -> Result<Vec<usize>, CycleError> {
+        let n = self.adj.len();
+        let mut in_degree = vec![0; n];
+
+        for edges in &self.adj {
+            for &(v, _) in edges {
+                in_degree[v] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..n).filter(|&u| in_degree[u] == 0).collect();
+        let mut order = Vec::new();
+
+        while let Some(u) = queue.pop_front() {
+            order.push(u);
+
+            for &(v, _) in &self.adj[u] {
+                in_degree[v] -= 1;
+
+                if in_degree[v] == 0 {
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        if order.len() == n {
+            Ok(order)
+        } else {
+            Err(CycleError)
+        }
+    }
+
+    fn has_cycle(&self) -> bool {
+        let mut color = vec![Color::White; self.adj.len()];
+
+        for u in 0..self.adj.len() {
+            if color[u] == Color::White && self.has_back_edge(u, &mut color) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // Reaching a gray vertex means we've found an edge back into the current path
+    fn has_back_edge(&self, u: usize, color: &mut Vec<Color>) -> bool {
+        color[u] = Color::Gray;
+
+        for &(v, _) in &self.adj[u] {
+            if color[v] == Color::Gray {
+                return true;
+            }
+            if color[v] == Color::White && self.has_back_edge(v, color) {
+                return true;
+            }
+        }
+
+        color[u] = Color::Black;
+        false
+    }
+}
+
+// Prim's algorithm: grow the tree from `start`, always adding the cheapest edge that
+// leaves it. Takes the adjacency list of an undirected graph (each edge stored both
+// ways) and returns the tree's edges as (from, to, weight). Vertices that can't be
+// reached from `start` are left out.
+fn prim(graph: &[Vec<(usize, u32)>], start: usize) -> Vec<(usize, usize, u32)> {
+    assert!(
+        start < graph.len(),
+        "prim: start is {} but the graph has {} vertices",
+        start,
+        graph.len()
+    );
+
+    let mut visited = vec![false; graph.len()];
+    let mut mst = Vec::new();
+    // Candidate edges ordered by weight, cheapest first
+    let mut heap = BinaryHeap::new();
+
+    visited[start] = true;
+    for &(v, weight) in &graph[start] {
+        heap.push(Reverse((weight, start, v)));
+    }
+
+    while let Some(Reverse((weight, u, v))) = heap.pop() {
+        // Both ends are already in the tree, so this edge would close a cycle
+        if visited[v] {
+            continue;
+        }
+
+        visited[v] = true;
+        mst.push((u, v, weight));
+
+        for &(next, next_weight) in &graph[v] {
+            if !visited[next] {
+                heap.push(Reverse((next_weight, v, next)));
+            }
+        }
+    }
+
+    mst
+}
+
+// Bellman-Ford: shortest distances from `source` over directed edges (u, v, weight),
+// None if a vertex can't be reached. A shortest path visits at most n vertices, so
+// relaxing every edge n - 1 times is enough. If an edge can still be relaxed after
+// that, some cycle keeps making paths cheaper and there is no shortest path.
+// O(VE), slower than Dijkstra but fine with negative weights.
+fn bellman_ford(
+    edges: &[(usize, usize, i32)],
+    n: usize,
+    source: usize,
+) -> Result<Vec<Option<i32>>, NegativeCycleError> {
+    assert!(
+        source < n,
+        "bellman_ford: source is {} but the graph has {} vertices",
+        source,
+        n
+    );
+
+    // Summed in i64, so a path can pass through totals an i32 can't hold, and a
+    // negative cycle can be driven down for every pass without wrapping
+    let mut dist: Vec<Option<i64>> = vec![None; n];
+    dist[source] = Some(0);
+
+    let relax = |dist: &mut Vec<Option<i64>>| {
+        let mut changed = false;
+
+        for &(u, v, weight) in edges {
+            let Some(du) = dist[u] else {
+                continue;
+            };
+
+            // Every dist is the weight of some walk from the source, and a simple path
+            // never gets below (n - 1) * i32::MIN. So sinking past i64::MIN takes a
+            // negative cycle, and rising past i64::MAX can't be a shortest path.
+            let candidate = match du.checked_add(i64::from(weight)) {
+                Some(candidate) => candidate,
+                None if weight < 0 => return Err(NegativeCycleError),
+                None => continue,
+            };
+
+            if dist[v].is_none_or(|best| candidate < best) {
+                dist[v] = Some(candidate);
+                changed = true;
+            }
+        }
+
+        Ok(changed)
+    };
+
+    // Only the final distances have to fit the i32 the caller gets back
+    let narrow = |dist: Vec<Option<i64>>| {
+        dist.into_iter()
+            .map(|d| {
+                d.map(|d| {
+                    i32::try_from(d).expect("bellman_ford: a shortest distance doesn't fit in i32")
+                })
+            })
+            .collect()
+    };
+
+    for _ in 1..n {
+        // Nothing changed in a whole pass, so nothing will in the next ones either
+        if !relax(&mut dist)? {
+            return Ok(narrow(dist));
+        }
+    }
+
+    if relax(&mut dist)? {
+        Err(NegativeCycleError)
+    } else {
+        Ok(narrow(dist))
+    }
+}
+
+// Floyd-Warshall: all-pairs shortest paths in O(V^3) over an adjacency matrix, where
+// dist[i][j] is the weight of the edge i -> j and None means there is no edge. After
+// round k, dist[i][j] is the shortest path that only passes through vertices 0..=k
+// on the way, so after the last round it's the shortest path overall. A negative
+// entry on the diagonal means a vertex is on a negative cycle, and then there is no
+// shortest path to report, so that's an error and the matrix is left half-updated.
+fn floyd_warshall(dist: &mut [Vec<Option<i64>>]) -> Result<(), NegativeCycleError> {
+    let n = dist.len();
+    assert!(
+        dist.iter().all(|row| row.len() == n),
+        "floyd_warshall needs a square matrix"
+    );
+
+    // Every vertex reaches itself for free
+    for (i, row) in dist.iter_mut().enumerate() {
+        if row[i].is_none() {
+            row[i] = Some(0);
+        }
+    }
+
+    for k in 0..n {
+        // Row k itself gets updated when dist[k][k] goes negative, so read a copy of it
+        // as it was after the last round
+        let through = dist[k].clone();
+
+        for row in dist.iter_mut() {
+            let Some(to_k) = row[k] else {
+                continue;
+            };
+
+            for (cell, &from_k) in row.iter_mut().zip(&through) {
+                if let Some(from_k) = from_k {
+                    let candidate = to_k
+                        .checked_add(from_k)
+                        .expect("floyd_warshall: a path's weight overflows i64");
+
+                    if cell.is_none_or(|best| candidate < best) {
+                        *cell = Some(candidate);
+                    }
+                }
+            }
+        }
+
+        // Stopping at the first negative cycle keeps every entry the weight of a path
+        // with no repeated vertex. Carrying on lets them double each round.
+        if (0..n).any(|i| dist[i][i].is_some_and(|d| d < 0)) {
+            return Err(NegativeCycleError);
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {
+    //   0 --- 1 --- 3
+    //   |     |
+    //   2 --- 4     5 --- 6
+    let mut graph = Graph::new(7);
+
+    graph.add_undirected_edge(0, 1);
+    graph.add_undirected_edge(0, 2);
+    graph.add_undirected_edge(1, 3);
+    graph.add_undirected_edge(1, 4);
+    graph.add_undirected_edge(2, 4);
+    graph.add_undirected_edge(5, 6);
+
+    println!("{:?}", graph.bfs(0)); // [0, 1, 2, 3, 4]
+    println!("{:?}", graph.dfs(0)); // [0, 1, 3, 4, 2]
+    println!("{:?}", graph.dfs_iterative(0)); // [0, 1, 3, 4, 2]
+
+    // 5 and 6 are disconnected from 0, so they're only reached when starting there
+    println!("{:?}", graph.bfs(5)); // [5, 6]
+    println!("{:?}", graph.dfs(6)); // [6, 5]
+
+    //        4
+    //   0 ------> 1
+    //   |       ^ |
+    // 1 |    2 /  | 1
+    //   v     /   v
+    //   2 ------> 3     4 (unreachable)
+    //        5
+    let mut weighted = Graph::new(5);
+
+    weighted.add_weighted_edge(0, 1, 4);
+    weighted.add_weighted_edge(0, 2, 1);
+    weighted.add_weighted_edge(2, 1, 2);
+    weighted.add_weighted_edge(1, 3, 1);
+    weighted.add_weighted_edge(2, 3, 5);
+
+    // 0 -> 2 -> 1 costs 3, beating the direct edge of 4, and 0 -> 2 -> 1 -> 3 costs 4
+    println!("{:?}", weighted.dijkstra(0)); // [Some(0), Some(3), Some(1), Some(4), None]
+
+    // Course prerequisites: 0 -> 2 means course 0 must be taken before course 2
+    let mut dag = Graph::new(6);
+
+    dag.add_edge(5, 2);
+    dag.add_edge(5, 0);
+    dag.add_edge(4, 0);
+    dag.add_edge(4, 1);
+    dag.add_edge(2, 3);
+    dag.add_edge(3, 1);
+
+    let order = dag.topological_sort().unwrap();
+    println!("{:?}", order); // [4, 5, 2, 0, 3, 1]
+
+    let mut position = vec![0; order.len()];
+    for (i, &u) in order.iter().enumerate() {
+        position[u] = i;
+    }
+    let respects_edges =
+        (0..dag.adj.len()).all(|u| dag.adj[u].iter().all(|&(v, _)| position[u] < position[v]));
+    assert!(respects_edges);
+
+    println!("Has cycle: {}", dag.has_cycle()); // Has cycle: false
+
+    dag.add_edge(1, 5); // 5 -> 2 -> 3 -> 1 -> 5
+    println!("{:?}", dag.topological_sort()); // Err(CycleError)
+    println!("Has cycle: {}", dag.has_cycle()); // Has cycle: true
+
+    let mut triangle = Graph::new(3);
+    triangle.add_edge(0, 1);
+    triangle.add_edge(1, 2);
+    triangle.add_edge(2, 0);
+    println!("Has cycle: {}", triangle.has_cycle()); // Has cycle: true
+
+    let mut self_loop = Graph::new(2);
+    self_loop.add_edge(0, 1);
+    self_loop.add_edge(1, 1);
+    println!("Has cycle: {}", self_loop.has_cycle()); // Has cycle: true
+
+    // The same graph as the Kruskal example in dsu.rs
+    //      2       3
+    //   0 ---- 1 ---- 2
+    //   |    / |     /
+    // 6 |  8/  |5   /7
+    //   | /    |   /
+    //   3 ---- 4 -
+    //      9
+    let edges = [
+        (0, 1, 2),
+        (0, 3, 6),
+        (1, 2, 3),
+        (1, 3, 8),
+        (1, 4, 5),
+        (2, 4, 7),
+        (3, 4, 9),
+    ];
+    let mut network = Graph::new(5);
+    for &(u, v, weight) in &edges {
+        network.add_weighted_edge(u, v, weight);
+        network.add_weighted_edge(v, u, weight);
+    }
+
+    let mst = prim(&network.adj, 0);
+    let total: u32 = mst.iter().map(|&(_, _, weight)| weight).sum();
+    println!("{:?}", mst); // [(0, 1, 2), (1, 2, 3), (1, 4, 5), (0, 3, 6)]
+    println!("Total weight: {}", total); // Total weight: 16, same as Kruskal's
+
+    // Starting elsewhere picks different edges in a different order, but the weight is the same
+    let from_four: u32 = prim(&network.adj, 4).iter().map(|&(_, _, w)| w).sum();
+    println!("Total weight: {}", from_four); // Total weight: 16
+
+    //        4
+    //   0 ------> 1
+    //   |         |
+    // 3 |         | -3
+    //   v         v
+    //   2 <-------+
+    //   |
+    // 2 |
+    //   v
+    //   3     4 (unreachable)
+    let negative = [(0, 1, 4), (0, 2, 3), (1, 2, -3), (2, 3, 2)];
+
+    // 0 -> 1 -> 2 costs 1, cheaper than the direct edge. Dijkstra would settle 2 at 3
+    // before ever looking at the negative edge.
+    println!("{:?}", bellman_ford(&negative, 5, 0));
+    // Ok([Some(0), Some(4), Some(1), Some(3), None])
+
+    // 1 -> 2 -> 1 costs -1, so going around it again is always cheaper
+    let cycle = [(0, 1, 1), (1, 2, -2), (2, 1, 1), (2, 3, 1)];
+    println!("{:?}", bellman_ford(&cycle, 4, 0)); // Err(NegativeCycleError)
+
+    // A negative cycle that can't be reached from the source doesn't matter
+    let unreachable_cycle = [(0, 1, 5), (2, 3, -2), (3, 2, 1)];
+    println!("{:?}", bellman_ford(&unreachable_cycle, 4, 0)); // Ok([Some(0), Some(5), None, None])
+
+    //      3        2
+    //   0 ---> 1 ---> 2     Plus 0 -> 2 costing 8 and 2 -> 0 costing 5
+    //   ^
+    // 1 |
+    //   3
+    let mut dist = vec![vec![None; 4]; 4];
+    dist[0][1] = Some(3);
+    dist[0][2] = Some(8);
+    dist[1][2] = Some(2);
+    dist[2][0] = Some(5);
+    dist[3][0] = Some(1);
+
+    floyd_warshall(&mut dist).unwrap();
+    for row in &dist {
+        println!("{:?}", row);
+    }
+    // [Some(0), Some(3), Some(5), None]  0 -> 1 -> 2 beats the direct edge of 8
+    // [Some(7), Some(0), Some(2), None]
+    // [Some(5), Some(8), Some(0), None]
+    // [Some(1), Some(4), Some(6), Some(0)]  Nothing leads back into 3
+
+    // Worked out by hand
+    let expected = vec![
+        vec![Some(0), Some(3), Some(5), None],
+        vec![Some(7), Some(0), Some(2), None],
+        vec![Some(5), Some(8), Some(0), None],
+        vec![Some(1), Some(4), Some(6), Some(0)],
+    ];
+    assert_eq!(dist, expected);
+
+    // The first graph: {0, 1, 2, 3, 4} and {5, 6}
+    println!("{:?}", graph.component_ids()); // [0, 0, 0, 0, 0, 1, 1]
+    println!("Components: {}", graph.connected_components()); // Components: 2
+
+    let mut complete = Graph::new(4);
+    for u in 0..4 {
+        for v in u + 1..4 {
+            complete.add_undirected_edge(u, v);
+        }
+    }
+    println!("Components: {}", complete.connected_components()); // Components: 1
+
+    let isolated = Graph::new(4);
+    println!("{:?}", isolated.component_ids()); // [0, 1, 2, 3]
+    println!("Components: {}", isolated.connected_components()); // Components: 4
+    println!("Components: {}", Graph::new(0).connected_components()); // Components: 0
+
+    // 0 - 1 - 2 - 3 - 0 alternates colors all the way around
+    let mut square = Graph::new(4);
+    for u in 0..4 {
+        square.add_undirected_edge(u, (u + 1) % 4);
+    }
+    println!("Bipartite: {}", square.is_bipartite()); // Bipartite: true
+
+    // 0 - 1 - 2 - 0 needs a third color to close the loop
+    let mut odd = Graph::new(3);
+    for u in 0..3 {
+        odd.add_undirected_edge(u, (u + 1) % 3);
+    }
+    println!("Bipartite: {}", odd.is_bipartite()); // Bipartite: false
+
+    // The first graph's only cycle, 0 - 1 - 4 - 2 - 0, has an even number of edges
+    println!("Bipartite: {}", graph.is_bipartite()); // Bipartite: true
+
+    // An even cycle and a triangle with no edges between them: one bad component is enough
+    let mut mixed = Graph::new(7);
+    for u in 0..4 {
+        mixed.add_undirected_edge(u, (u + 1) % 4);
+    }
+    mixed.add_undirected_edge(4, 5);
+    mixed.add_undirected_edge(5, 6);
+    mixed.add_undirected_edge(6, 4);
+    println!("Bipartite: {}", mixed.is_bipartite()); // Bipartite: false
+    println!("Bipartite: {}", Graph::new(3).is_bipartite()); // Bipartite: true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn undirected(n: usize, edges: &[(usize, usize)]) -> Graph {
+        let mut graph = Graph::new(n);
+        for &(u, v) in edges {
+            graph.add_undirected_edge(u, v);
+        }
+        graph
+    }
+
+    #[test]
+    fn bfs_visits_level_by_level() {
+        //   0 - 1 - 3
+        //   |   |
+        //   2 - 4   5
+        let graph = undirected(6, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 4)]);
+
+        assert_eq!(graph.bfs(0), vec![0, 1, 2, 3, 4]);
+        assert_eq!(graph.bfs(3), vec![3, 1, 0, 4, 2]);
+        assert_eq!(graph.bfs(5), vec![5]);
+    }
+
+    #[test]
+    fn both_dfs_versions_agree() {
+        let graph = undirected(6, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 4)]);
+
+        assert_eq!(graph.dfs(0), vec![0, 1, 3, 4, 2]);
+        for start in 0..6 {
+            assert_eq!(graph.dfs(start), graph.dfs_iterative(start));
+        }
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_detours() {
+        let mut graph = Graph::new(5);
+        graph.add_weighted_edge(0, 1, 4);
+        graph.add_weighted_edge(0, 2, 1);
+        graph.add_weighted_edge(2, 1, 2);
+        graph.add_weighted_edge(1, 3, 1);
+        graph.add_weighted_edge(2, 3, 5);
+
+        assert_eq!(
+            graph.dijkstra(0),
+            vec![Some(0), Some(3), Some(1), Some(4), None]
+        );
+        assert_eq!(graph.dijkstra(3), vec![None, None, None, Some(0), None]);
+    }
+
+    #[test]
+    fn dijkstra_skips_paths_that_overflow() {
+        // 0 -> 1 -> 2 would cost more than u32::MAX, 0 -> 2 fits
+        let mut graph = Graph::new(4);
+        graph.add_weighted_edge(0, 1, u32::MAX);
+        graph.add_weighted_edge(1, 2, 1);
+        graph.add_weighted_edge(0, 2, 7);
+        graph.add_weighted_edge(1, 3, 1);
+
+        assert_eq!(
+            graph.dijkstra(0),
+            vec![Some(0), Some(u32::MAX), Some(7), None]
+        );
+    }
+
+    fn directed(n: usize, edges: &[(usize, usize)]) -> Graph {
+        let mut graph = Graph::new(n);
+        for &(u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    #[test]
+    fn topological_sort_puts_every_edge_forwards() {
+        let dag = directed(6, &[(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)]);
+        let order = dag.topological_sort().unwrap();
+
+        assert_eq!(order, vec![4, 5, 2, 0, 3, 1]);
+
+        let mut position = vec![0; order.len()];
+        for (i, &u) in order.iter().enumerate() {
+            position[u] = i;
+        }
+        for u in 0..6 {
+            for &(v, _) in &dag.adj[u] {
+                assert!(position[u] < position[v], "{} -> {} goes backwards", u, v);
+            }
+        }
+    }
+
+    #[test]
+    fn topological_sort_rejects_cycles() {
+        let cyclic = directed(4, &[(0, 1), (1, 2), (2, 3), (3, 1)]);
+        assert!(cyclic.topological_sort().is_err());
+
+        assert_eq!(Graph::new(3).topological_sort().unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn has_cycle_finds_back_edges() {
+        assert!(directed(3, &[(0, 1), (1, 2), (2, 0)]).has_cycle());
+        assert!(directed(2, &[(0, 1), (1, 1)]).has_cycle());
+
+        // The cycle is only reachable from a later starting vertex
+        assert!(directed(4, &[(0, 1), (2, 3), (3, 2)]).has_cycle());
+    }
+
+    #[test]
+    fn has_cycle_ignores_cross_edges() {
+        // 0 -> 1 -> 3 and 0 -> 2 -> 3 meet at 3 without forming a cycle
+        assert!(!directed(4, &[(0, 1), (0, 2), (1, 3), (2, 3)]).has_cycle());
+        assert!(!Graph::new(3).has_cycle());
+    }
+
+    // Adjacency list with every weighted edge stored both ways
+    fn weighted(n: usize, edges: &[(usize, usize, u32)]) -> Vec<Vec<(usize, u32)>> {
+        let mut adj = vec![Vec::new(); n];
+        for &(u, v, weight) in edges {
+            adj[u].push((v, weight));
+            adj[v].push((u, weight));
+        }
+        adj
+    }
+
+    fn total(mst: &[(usize, usize, u32)]) -> u32 {
+        mst.iter().map(|&(_, _, weight)| weight).sum()
+    }
+
+    #[test]
+    fn prim_finds_a_minimum_spanning_tree() {
+        let edges = [
+            (0, 1, 2),
+            (0, 3, 6),
+            (1, 2, 3),
+            (1, 3, 8),
+            (1, 4, 5),
+            (2, 4, 7),
+            (3, 4, 9),
+        ];
+        let adj = weighted(5, &edges);
+
+        let mst = prim(&adj, 0);
+        assert_eq!(mst, vec![(0, 1, 2), (1, 2, 3), (1, 4, 5), (0, 3, 6)]);
+
+        // Any start gives a tree of the same weight with n - 1 edges
+        for start in 0..5 {
+            let mst = prim(&adj, start);
+            assert_eq!(mst.len(), 4);
+            assert_eq!(total(&mst), 16);
+        }
+    }
+
+    #[test]
+    fn prim_leaves_out_unreachable_vertices() {
+        let adj = weighted(5, &[(0, 1, 4), (1, 2, 1), (3, 4, 2)]);
+
+        assert_eq!(prim(&adj, 0), vec![(0, 1, 4), (1, 2, 1)]);
+        assert_eq!(prim(&adj, 3), vec![(3, 4, 2)]);
+        assert!(prim(&weighted(1, &[]), 0).is_empty());
+    }
+
+    #[test]
+    fn prim_takes_the_cheaper_of_parallel_edges() {
+        let adj = weighted(2, &[(0, 1, 9), (0, 1, 2)]);
+        assert_eq!(prim(&adj, 0), vec![(0, 1, 2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "prim: start is 3 but the graph has 3 vertices")]
+    fn prim_rejects_a_start_outside_the_graph() {
+        prim(&weighted(3, &[(0, 1, 1)]), 3);
+    }
+
+    #[test]
+    fn bellman_ford_follows_negative_edges() {
+        let negative = [(0, 1, 4), (0, 2, 3), (1, 2, -3), (2, 3, 2)];
+        assert_eq!(
+            bellman_ford(&negative, 5, 0),
+            Ok(vec![Some(0), Some(4), Some(1), Some(3), None])
+        );
+        assert_eq!(
+            bellman_ford(&negative, 5, 1),
+            Ok(vec![None, Some(0), Some(-3), Some(-1), None])
+        );
+        assert_eq!(bellman_ford(&[], 1, 0), Ok(vec![Some(0)]));
+    }
+
+    #[test]
+    fn bellman_ford_reports_reachable_negative_cycles_only() {
+        let cycle = [(0, 1, 1), (1, 2, -2), (2, 1, 1), (2, 3, 1)];
+        assert_eq!(bellman_ford(&cycle, 4, 0), Err(NegativeCycleError));
+
+        let unreachable_cycle = [(0, 1, 5), (2, 3, -2), (3, 2, 1)];
+        assert_eq!(
+            bellman_ford(&unreachable_cycle, 4, 0),
+            Ok(vec![Some(0), Some(5), None, None])
+        );
+
+        // Every pass drives the cycle another 10^9 lower, past what an i32 can hold,
+        // before the last pass gets to report it
+        let deep_cycle = [(0, 1, 0), (1, 2, -1_000_000_000), (2, 1, 0), (2, 3, 0)];
+        assert_eq!(bellman_ford(&deep_cycle, 4, 0), Err(NegativeCycleError));
+
+        // A cycle of weight 0 isn't negative
+        let zero_cycle = [(0, 1, 2), (1, 0, -2)];
+        assert_eq!(bellman_ford(&zero_cycle, 2, 0), Ok(vec![Some(0), Some(2)]));
+    }
+
+    #[test]
+    fn bellman_ford_agrees_with_dijkstra_on_non_negative_weights() {
+        let edges = [(0, 1, 7), (0, 2, 9), (0, 5, 14), (1, 2, 10), (1, 3, 15)];
+        let edges = [&edges[..], &[(2, 3, 11), (2, 5, 2), (3, 4, 6), (4, 5, 9)]].concat();
+
+        let mut graph = Graph::new(6);
+        for &(u, v, weight) in &edges {
+            graph.add_weighted_edge(u, v, weight as u32);
+        }
+        let expected: Vec<Option<i32>> = graph
+            .dijkstra(0)
+            .into_iter()
+            .map(|d| d.map(|d| d as i32))
+            .collect();
+
+        assert_eq!(bellman_ford(&edges, 6, 0), Ok(expected));
+    }
+
+    #[test]
+    fn bellman_ford_tolerates_totals_beyond_i32_on_the_way() {
+        // The first pass reaches 2 through 1 at 2 * i32::MAX before the direct edge
+        // brings it back down
+        let edges = [(0, 1, i32::MAX), (1, 2, i32::MAX), (0, 2, 0)];
+        assert_eq!(
+            bellman_ford(&edges, 3, 0),
+            Ok(vec![Some(0), Some(i32::MAX), Some(0)])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "bellman_ford: a shortest distance doesn't fit in i32")]
+    fn bellman_ford_panics_instead_of_wrapping() {
+        bellman_ford(&[(0, 1, i32::MAX), (1, 2, 1)], 3, 0).ok();
+    }
+
+    #[test]
+    #[should_panic(expected = "bellman_ford: source is 4 but the graph has 4 vertices")]
+    fn bellman_ford_rejects_a_source_outside_the_graph() {
+        bellman_ford(&[(0, 1, 1)], 4, 4).ok();
+    }
+
+    // n x n matrix with only the given directed edges filled in
+    fn matrix(n: usize, edges: &[(usize, usize, i64)]) -> Vec<Vec<Option<i64>>> {
+        let mut dist = vec![vec![None; n]; n];
+        for &(u, v, weight) in edges {
+            dist[u][v] = Some(weight);
+        }
+        dist
+    }
+
+    #[test]
+    fn floyd_warshall_finds_every_shortest_path() {
+        let mut dist = matrix(4, &[(0, 1, 3), (0, 2, 8), (1, 2, 2), (2, 0, 5), (3, 0, 1)]);
+        floyd_warshall(&mut dist).unwrap();
+
+        assert_eq!(
+            dist,
+            vec![
+                vec![Some(0), Some(3), Some(5), None],
+                vec![Some(7), Some(0), Some(2), None],
+                vec![Some(5), Some(8), Some(0), None],
+                vec![Some(1), Some(4), Some(6), Some(0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn floyd_warshall_handles_negative_edges() {
+        let mut dist = matrix(3, &[(0, 1, 4), (0, 2, 3), (1, 2, -3)]);
+        floyd_warshall(&mut dist).unwrap();
+
+        assert_eq!(dist[0], vec![Some(0), Some(4), Some(1)]);
+        assert_eq!(dist[1], vec![None, Some(0), Some(-3)]);
+    }
+
+    #[test]
+    fn floyd_warshall_agrees_with_bellman_ford() {
+        let edges = [(0, 1, 6), (0, 2, 7), (1, 2, 8), (1, 3, 5), (1, 4, -4)];
+        let edges = [
+            &edges[..],
+            &[(2, 3, -3), (2, 4, 9), (3, 1, -2), (4, 0, 2), (4, 3, 7)],
+        ]
+        .concat();
+
+        let wide: Vec<(usize, usize, i64)> =
+            edges.iter().map(|&(u, v, w)| (u, v, w as i64)).collect();
+        let mut dist = matrix(5, &wide);
+        floyd_warshall(&mut dist).unwrap();
+
+        for (source, row) in dist.iter().enumerate() {
+            let expected: Vec<Option<i64>> = bellman_ford(&edges, 5, source)
+                .unwrap()
+                .into_iter()
+                .map(|d| d.map(i64::from))
+                .collect();
+            assert_eq!(*row, expected);
+        }
+    }
+
+    #[test]
+    fn floyd_warshall_fills_the_diagonal_and_handles_no_vertices() {
+        let mut dist = matrix(2, &[(0, 1, 1)]);
+        floyd_warshall(&mut dist).unwrap();
+        assert_eq!(dist, vec![vec![Some(0), Some(1)], vec![None, Some(0)]]);
+
+        let mut empty: Vec<Vec<Option<i64>>> = Vec::new();
+        floyd_warshall(&mut empty).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn floyd_warshall_reports_negative_cycles() {
+        // 1 -> 2 -> 1 costs -1, and 3 only hangs off it
+        let mut dist = matrix(4, &[(0, 1, 1), (1, 2, -3), (2, 1, 2), (2, 3, 1)]);
+        assert_eq!(floyd_warshall(&mut dist), Err(NegativeCycleError));
+
+        // A self-loop is a cycle too
+        let mut dist = matrix(2, &[(0, 1, 1), (1, 1, -1)]);
+        assert_eq!(floyd_warshall(&mut dist), Err(NegativeCycleError));
+
+        // Weight 0 isn't negative
+        let mut dist = matrix(2, &[(0, 1, 2), (1, 0, -2)]);
+        assert_eq!(floyd_warshall(&mut dist), Ok(()));
+        assert_eq!(dist, vec![vec![Some(0), Some(2)], vec![Some(-2), Some(0)]]);
+    }
+
+    #[test]
+    fn floyd_warshall_catches_a_deep_negative_cycle_before_it_overflows() {
+        // Round 0 puts 2w on 1's diagonal. Round 1 would add that to itself and
+        // overflow, so the cycle has to be reported before it starts.
+        let w = i64::MIN / 3;
+        let mut dist = matrix(3, &[(0, 1, w), (1, 0, w), (1, 2, 0)]);
+        assert_eq!(floyd_warshall(&mut dist), Err(NegativeCycleError));
+    }
+
+    #[test]
+    #[should_panic(expected = "floyd_warshall: a path's weight overflows i64")]
+    fn floyd_warshall_panics_instead_of_wrapping() {
+        let mut dist = matrix(3, &[(0, 1, i64::MAX), (1, 2, 1)]);
+        floyd_warshall(&mut dist).ok();
+    }
+
+    #[test]
+    #[should_panic(expected = "floyd_warshall needs a square matrix")]
+    fn floyd_warshall_rejects_a_matrix_that_isnt_square() {
+        let mut dist = vec![vec![None; 3], vec![None; 2], vec![None; 3]];
+        floyd_warshall(&mut dist).ok();
+    }
+
+    #[test]
+    fn component_ids_are_numbered_by_lowest_vertex() {
+        let graph = undirected(7, &[(5, 6), (0, 1), (1, 4), (4, 2), (2, 0), (3, 2)]);
+        assert_eq!(graph.component_ids(), vec![0, 0, 0, 0, 0, 1, 1]);
+        assert_eq!(graph.connected_components(), 2);
+
+        let graph = undirected(5, &[(4, 0), (1, 3)]);
+        assert_eq!(graph.component_ids(), vec![0, 1, 2, 1, 0]);
+        assert_eq!(graph.connected_components(), 3);
+    }
+
+    #[test]
+    fn connected_components_counts_isolated_vertices() {
+        let isolated = Graph::new(4);
+        assert_eq!(isolated.component_ids(), vec![0, 1, 2, 3]);
+        assert_eq!(isolated.connected_components(), 4);
+
+        assert!(Graph::new(0).component_ids().is_empty());
+        assert_eq!(Graph::new(0).connected_components(), 0);
+    }
+
+    #[test]
+    fn a_connected_graph_is_one_component() {
+        let complete = undirected(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+        assert_eq!(complete.component_ids(), vec![0; 4]);
+        assert_eq!(complete.connected_components(), 1);
+
+        let self_loop = undirected(1, &[(0, 0)]);
+        assert_eq!(self_loop.connected_components(), 1);
+    }
+
+    // 0 - 1 - ... - (n - 1) - 0
+    fn cycle(n: usize) -> Graph {
+        let edges: Vec<(usize, usize)> = (0..n).map(|u| (u, (u + 1) % n)).collect();
+        undirected(n, &edges)
+    }
+
+    #[test]
+    fn even_cycles_are_bipartite_and_odd_ones_are_not() {
+        for n in 3..10 {
+            assert_eq!(cycle(n).is_bipartite(), n % 2 == 0, "cycle of {}", n);
+        }
+    }
+
+    #[test]
+    fn trees_and_edgeless_graphs_are_bipartite() {
+        let tree = undirected(6, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)]);
+        assert!(tree.is_bipartite());
+        assert!(Graph::new(3).is_bipartite());
+        assert!(Graph::new(0).is_bipartite());
+    }
+
+    #[test]
+    fn one_odd_component_makes_the_graph_not_bipartite() {
+        let mixed = undirected(7, &[(0, 1), (1, 2), (2, 3), (3, 0), (4, 5), (5, 6), (6, 4)]);
+        assert!(!mixed.is_bipartite());
+
+        // The odd cycle is in a component that isn't the first one searched
+        let later = undirected(5, &[(0, 1), (2, 3), (3, 4), (4, 2)]);
+        assert!(!later.is_bipartite());
+    }
+
+    #[test]
+    fn a_self_loop_is_not_bipartite() {
+        assert!(!undirected(1, &[(0, 0)]).is_bipartite());
+    }
+
+    #[test]
+    #[should_panic(expected = "bfs: start is 3 but the graph has 3 vertices")]
+    fn bfs_rejects_a_start_outside_the_graph() {
+        undirected(3, &[(0, 1)]).bfs(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "dfs: start is 3 but the graph has 3 vertices")]
+    fn dfs_rejects_a_start_outside_the_graph() {
+        undirected(3, &[(0, 1)]).dfs(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "dfs_iterative: start is 0 but the graph has 0 vertices")]
+    fn dfs_iterative_rejects_a_start_outside_the_graph() {
+        Graph::new(0).dfs_iterative(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "dijkstra: source is 5 but the graph has 2 vertices")]
+    fn dijkstra_rejects_a_source_outside_the_graph() {
+        let mut graph = Graph::new(2);
+        graph.add_weighted_edge(0, 1, 1);
+        graph.dijkstra(5);
+    }
+}
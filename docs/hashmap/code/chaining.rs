@@ -0,0 +1,162 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+const MAX_LOAD_FACTOR: f64 = 0.75;
+
+// Separate chaining: every bucket is a small list of the (key, value) pairs
+// whose keys hash to that bucket. Collisions just make a bucket longer.
+#[allow(dead_code)]
+struct HashMap<K, V> {
+    buckets: Vec<Vec<(K, V)>>,
+    len: usize,
+}
+
+#[allow(dead_code)]
+impl<K: Hash + Eq, V> HashMap<K, V> {
+    fn new() -> Self {
+        HashMap::with_capacity(8)
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        let mut buckets = Vec::with_capacity(capacity);
+        buckets.resize_with(capacity.max(1), Vec::new);
+
+        HashMap { buckets, len: 0 }
+    }
+
+    fn bucket_index(&self, key: &K) -> usize {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+
+        (hasher.finish() % self.buckets.len() as u64) as usize
+    }
+
+    // Returns the old value if the key was already present
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let index = self.bucket_index(&key);
+
+        for (k, v) in self.buckets[index].iter_mut() {
+            if *k == key {
+                return Some(std::mem::replace(v, value));
+            }
+        }
+
+        self.buckets[index].push((key, value));
+        self.len += 1;
+
+        if self.len as f64 / self.buckets.len() as f64 > MAX_LOAD_FACTOR {
+            self.resize();
+        }
+
+        None
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        let index = self.bucket_index(key);
+
+        self.buckets[index]
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.bucket_index(key);
+        let bucket = &mut self.buckets[index];
+        let position = bucket.iter().position(|(k, _)| k == key)?;
+
+        self.len -= 1;
+
+        // Order inside a bucket doesn't matter, so swap_remove avoids shifting
+        Some(bucket.swap_remove(position).1)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    // Double the bucket count and rehash every entry, since bucket indices
+    // depend on the number of buckets
+    fn resize(&mut self) {
+        let mut bigger = HashMap::with_capacity(self.buckets.len() * 2);
+
+        for bucket in self.buckets.drain(..) {
+            for (key, value) in bucket {
+                let index = bigger.bucket_index(&key);
+                bigger.buckets[index].push((key, value));
+            }
+        }
+
+        self.buckets = bigger.buckets;
+    }
+}
+
+fn main() {
+    // Starting with one bucket means keys collide until the table has grown a few times
+    let mut map = HashMap::with_capacity(1);
+
+    map.insert("apple", 3); // Load factor 1.0 > 0.75, grows to 2 buckets
+    map.insert("banana", 5); // Load factor 1.0 > 0.75, grows to 4 buckets
+    map.insert("cherry", 7); // Load factor 0.75, no resize
+    println!("Buckets: {}, length: {}", map.buckets.len(), map.len()); // Buckets: 4, length: 3
+
+    println!("{:?}", map.get(&"banana")); // Some(5)
+    println!("{:?}", map.get(&"durian")); // None
+
+    println!("{:?}", map.insert("apple", 10)); // Some(3)
+    println!("{:?}", map.get(&"apple")); // Some(10)
+    println!("Length: {}", map.len()); // Length: 3
+
+    println!("{:?}", map.remove(&"banana")); // Some(5)
+    println!("{:?}", map.remove(&"banana")); // None
+    println!("{:?}", map.get(&"banana")); // None
+    println!("Length: {}", map.len()); // Length: 2
+
+    let mut squares = HashMap::new();
+    for i in 0..100 {
+        squares.insert(i, i * i);
+    }
+    assert!((0..100).all(|i| squares.get(&i) == Some(&(i * i))));
+    println!("Buckets: {}", squares.buckets.len()); // Buckets: 256
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_overwrites_and_returns_the_old_value() {
+        let mut map = HashMap::new();
+
+        assert_eq!(map.insert("apple", 3), None);
+        assert_eq!(map.insert("apple", 10), Some(3));
+        assert_eq!(map.get(&"apple"), Some(&10));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn remove_only_takes_present_keys() {
+        let mut map = HashMap::new();
+        map.insert(1, "one");
+        map.insert(2, "two");
+
+        assert_eq!(map.remove(&1), Some("one"));
+        assert_eq!(map.remove(&1), None);
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.get(&2), Some(&"two"));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn resizing_keeps_every_entry_reachable() {
+        let mut map = HashMap::with_capacity(1);
+        for i in 0..1000 {
+            map.insert(i, i * i);
+        }
+
+        assert_eq!(map.len(), 1000);
+        assert!(map.len() as f64 / map.buckets.len() as f64 <= MAX_LOAD_FACTOR);
+        assert!((0..1000).all(|i| map.get(&i) == Some(&(i * i))));
+        assert_eq!(map.get(&1000), None);
+    }
+}
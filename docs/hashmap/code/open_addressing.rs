@@ -0,0 +1,251 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+const MAX_LOAD_FACTOR: f64 = 0.7;
+
+// A removed entry can't just become Empty: a lookup stops at the first Empty slot,
+// so any key that was pushed past the removed one would become unreachable.
+// A Tombstone says "keep probing" to lookups but can be reused by inserts.
+#[allow(dead_code)]
+enum Slot<K, V> {
+    Empty,
+    Occupied(K, V),
+    Tombstone,
+}
+
+#[allow(dead_code)]
+struct HashMap<K, V> {
+    slots: Vec<Slot<K, V>>,
+    len: usize,
+    tombstones: usize,
+}
+
+#[allow(dead_code)]
+impl<K: Hash + Eq, V> HashMap<K, V> {
+    fn new() -> Self {
+        HashMap::with_capacity(8)
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        let mut slots = Vec::with_capacity(capacity);
+        slots.resize_with(capacity.max(1), || Slot::Empty);
+
+        HashMap {
+            slots,
+            len: 0,
+            tombstones: 0,
+        }
+    }
+
+    fn home_index(&self, key: &K) -> usize {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+
+        (hasher.finish() % self.slots.len() as u64) as usize
+    }
+
+    // Linear probing: start at the key's home slot and step forward one slot at a
+    // time (wrapping around) until the key or an Empty slot is found
+    fn find_index(&self, key: &K) -> Option<usize> {
+        let capacity = self.slots.len();
+        let home = self.home_index(key);
+
+        for step in 0..capacity {
+            let i = (home + step) % capacity;
+
+            match &self.slots[i] {
+                Slot::Empty => return None,
+                Slot::Occupied(k, _) if k == key => return Some(i),
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    // Returns the old value if the key was already present
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        // Overwriting doesn't take up a new slot, so it never needs a resize
+        if let Some(i) = self.find_index(&key) {
+            if let Slot::Occupied(_, v) = &mut self.slots[i] {
+                return Some(std::mem::replace(v, value));
+            }
+        }
+
+        // Tombstones take up slots too, so they count towards the load factor.
+        // This guarantees there is always an Empty slot to end a probe.
+        if (self.len + self.tombstones + 1) as f64 / self.slots.len() as f64 > MAX_LOAD_FACTOR {
+            self.resize();
+        }
+
+        // Not present: reuse the first Tombstone on the probe path, or else the Empty slot
+        let capacity = self.slots.len();
+        let home = self.home_index(&key);
+
+        for step in 0..capacity {
+            let i = (home + step) % capacity;
+
+            match self.slots[i] {
+                Slot::Occupied(..) => continue,
+                Slot::Tombstone => self.tombstones -= 1,
+                Slot::Empty => {}
+            }
+
+            self.slots[i] = Slot::Occupied(key, value);
+            self.len += 1;
+            break;
+        }
+
+        None
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        match &self.slots[self.find_index(key)?] {
+            Slot::Occupied(_, v) => Some(v),
+            _ => None,
+        }
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let i = self.find_index(key)?;
+
+        match std::mem::replace(&mut self.slots[i], Slot::Tombstone) {
+            Slot::Occupied(_, v) => {
+                self.len -= 1;
+                self.tombstones += 1;
+                Some(v)
+            }
+            _ => None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    // Rehash, dropping every tombstone along the way. If the live entries plus the one
+    // being inserted fit under the load factor on their own, the table was only full of
+    // tombstones and keeps its size. Otherwise it doubles.
+    fn resize(&mut self) {
+        let old = std::mem::take(&mut self.slots);
+        let crowded = (self.len + 1) as f64 / old.len() as f64 > MAX_LOAD_FACTOR;
+        let capacity = if crowded { old.len() * 2 } else { old.len() };
+
+        *self = HashMap::with_capacity(capacity);
+
+        for slot in old {
+            if let Slot::Occupied(k, v) = slot {
+                self.insert(k, v);
+            }
+        }
+    }
+}
+
+// Every SameHash key hashes identically, which forces all of them into one probe chain
+#[derive(PartialEq, Eq, Debug)]
+struct SameHash(&'static str);
+
+impl Hash for SameHash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        0.hash(state);
+    }
+}
+
+fn main() {
+    let mut map = HashMap::with_capacity(8);
+
+    // Slots shown starting from the shared home slot
+    map.insert(SameHash("a"), 1); // [a] [ ] [ ]
+    map.insert(SameHash("b"), 2); // [a] [b] [ ]
+    map.insert(SameHash("c"), 3); // [a] [b] [c]
+
+    let removed = map.remove(&SameHash("b")); // [a] [TOMBSTONE] [c]
+    println!("{:?}", removed); // Some(2)
+
+    // If b's slot were simply emptied, the probe for c would stop there
+    println!("{:?}", map.get(&SameHash("c"))); // Some(3)
+    println!("{:?}", map.get(&SameHash("b"))); // None
+
+    map.insert(SameHash("d"), 4); // [a] [d] [c], d reuses the tombstone
+    println!("{:?}", map.get(&SameHash("d"))); // Some(4)
+    println!("{:?}", map.get(&SameHash("c"))); // Some(3)
+    println!("Length: {}", map.len()); // Length: 3
+
+    println!("{:?}", map.insert(SameHash("c"), 30)); // Some(3)
+    println!("{:?}", map.get(&SameHash("c"))); // Some(30)
+
+    let mut squares = HashMap::new();
+    for i in 0..100 {
+        squares.insert(i, i * i);
+    }
+    for i in (0..100).step_by(2) {
+        squares.remove(&i);
+    }
+    let odds_found = (1..100)
+        .step_by(2)
+        .all(|i| squares.get(&i) == Some(&(i * i)));
+    assert!(odds_found);
+    println!("Length: {}", squares.len()); // Length: 50
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tombstones_keep_later_keys_reachable() {
+        let mut map = HashMap::with_capacity(8);
+        map.insert(SameHash("a"), 1);
+        map.insert(SameHash("b"), 2);
+        map.insert(SameHash("c"), 3);
+
+        assert_eq!(map.remove(&SameHash("b")), Some(2));
+        assert_eq!(map.get(&SameHash("c")), Some(&3));
+        assert_eq!(map.get(&SameHash("b")), None);
+
+        // d reuses b's tombstone
+        map.insert(SameHash("d"), 4);
+        assert_eq!(map.tombstones, 0);
+        assert_eq!(map.get(&SameHash("d")), Some(&4));
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn overwriting_a_key_never_resizes() {
+        let mut map = HashMap::with_capacity(10);
+        for i in 0..7 {
+            map.insert(i, i);
+        }
+
+        // 7 of 10 slots are used, one more key would go over the load factor
+        for i in 0..7 {
+            assert_eq!(map.insert(i, i * 10), Some(i));
+        }
+        assert_eq!(map.slots.len(), 10);
+        assert_eq!(map.get(&3), Some(&30));
+    }
+
+    #[test]
+    fn insert_remove_churn_does_not_grow_the_table() {
+        let mut map = HashMap::new();
+        for i in 0..100_000 {
+            map.insert(i, i);
+            assert_eq!(map.remove(&i), Some(i));
+        }
+
+        assert_eq!(map.len(), 0);
+        assert_eq!(map.slots.len(), 8);
+    }
+
+    #[test]
+    fn growing_keeps_every_entry_reachable() {
+        let mut map = HashMap::with_capacity(1);
+        for i in 0..1000 {
+            map.insert(i, i * i);
+        }
+
+        assert_eq!(map.len(), 1000);
+        assert!(map.len() as f64 / map.slots.len() as f64 <= MAX_LOAD_FACTOR);
+        assert!((0..1000).all(|i| map.get(&i) == Some(&(i * i))));
+    }
+}
@@ -0,0 +1,363 @@
+use std::cmp::Ordering;
+
+// The heap is a complete binary tree stored level by level in a Vec.
+// For the node at index i: parent = (i - 1) / 2, left = 2i + 1, right = 2i + 2.
+#[allow(dead_code)]
+struct MinHeap {
+    data: Vec<i32>,
+}
+
+#[allow(dead_code)]
+impl MinHeap {
+    fn new() -> Self {
+        MinHeap { data: Vec::new() }
+    }
+
+    // Bottom-up heapify: every leaf is already a valid heap, so sift down each
+    // parent from the last one back to the root. This is O(n), not O(n log n).
+    fn from_vec(data: Vec<i32>) -> Self {
+        let mut heap = MinHeap { data };
+
+        for i in (0..heap.data.len() / 2).rev() {
+            heap.sift_down(i);
+        }
+
+        heap
+    }
+
+    fn push(&mut self, value: i32) {
+        self.data.push(value);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    fn pop(&mut self) -> Option<i32> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        // Move the last element to the root, then let it sink to its place
+        let last = self.data.len() - 1;
+        self.data.swap(0, last);
+        let min = self.data.pop();
+
+        if !self.data.is_empty() {
+            self.sift_down(0);
+        }
+
+        min
+    }
+
+    fn peek(&self) -> Option<&i32> {
+        self.data.first()
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn sift_up(&mut self, i: usize) {
+        sift_up(&mut self.data, i, &|a, b| a < b);
+    }
+
+    fn sift_down(&mut self, i: usize) {
+        let len = self.data.len();
+        sift_down(&mut self.data, i, len, &|a, b| a < b);
+    }
+
+    fn is_valid(&self) -> bool {
+        (1..self.data.len()).all(|i| self.data[(i - 1) / 2] <= self.data[i])
+    }
+}
+
+// Every heap here shares these two. before(a, b) says whether a belongs above b, so
+// `<` keeps the smallest element at the root and `>` the largest.
+fn sift_up<T>(data: &mut [T], mut i: usize, before: &impl Fn(&T, &T) -> bool) {
+    while i > 0 {
+        let parent = (i - 1) / 2;
+
+        if !before(&data[i], &data[parent]) {
+            break;
+        }
+
+        data.swap(i, parent);
+        i = parent;
+    }
+}
+
+// Sift down within data[..len], so heapsort can leave its sorted tail alone
+fn sift_down<T>(data: &mut [T], mut i: usize, len: usize, before: &impl Fn(&T, &T) -> bool) {
+    loop {
+        let left = 2 * i + 1;
+        let right = 2 * i + 2;
+        let mut first = i;
+
+        if left < len && before(&data[left], &data[first]) {
+            first = left;
+        }
+        if right < len && before(&data[right], &data[first]) {
+            first = right;
+        }
+
+        if first == i {
+            break;
+        }
+
+        data.swap(i, first);
+        i = first;
+    }
+}
+
+// Same array layout as MinHeap, but the order comes from cmp instead of i32's `<`.
+// Like std's BinaryHeap, pop returns the greatest element according to cmp, so
+// |a, b| a.cmp(b) gives a max-queue and |a, b| b.cmp(a) a min-queue.
+#[allow(dead_code)]
+struct PriorityQueue<T, F> {
+    heap: Vec<T>,
+    cmp: F,
+}
+
+#[allow(dead_code)]
+impl<T, F> PriorityQueue<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn new(cmp: F) -> Self {
+        PriorityQueue {
+            heap: Vec::new(),
+            cmp,
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        self.heap.push(value);
+
+        // The greater element according to cmp sits above
+        let before = |a: &T, b: &T| (self.cmp)(a, b) == Ordering::Greater;
+        let last = self.heap.len() - 1;
+        sift_up(&mut self.heap, last, &before);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let last = self.heap.len() - 1;
+        self.heap.swap(0, last);
+        let top = self.heap.pop();
+
+        let before = |a: &T, b: &T| (self.cmp)(a, b) == Ordering::Greater;
+        let len = self.heap.len();
+        sift_down(&mut self.heap, 0, len, &before);
+
+        top
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.heap.first()
+    }
+
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+#[derive(Debug)]
+struct Task {
+    name: &'static str,
+    priority: u32,
+}
+
+// Ascending heapsort needs the largest element at the root, so this builds a max-heap
+// in place, then repeatedly swaps the root to the end and shrinks the heap by one.
+// Flipping the `>` below sorts in descending order instead.
+fn heap_sort(data: &mut [i32]) {
+    let len = data.len();
+    let before = |a: &i32, b: &i32| a > b;
+
+    for i in (0..len / 2).rev() {
+        sift_down(data, i, len, &before);
+    }
+
+    for end in (1..len).rev() {
+        data.swap(0, end);
+        sift_down(data, 0, end, &before);
+    }
+}
+
+fn main() {
+    let mut heap = MinHeap::new();
+
+    for value in [40, 10, 30, 50, 20] {
+        heap.push(value);
+    }
+
+    println!("Peek: {:?}", heap.peek()); // Peek: Some(10)
+    println!("Length: {}", heap.len()); // Length: 5
+
+    let mut popped = Vec::new();
+    while let Some(value) = heap.pop() {
+        popped.push(value);
+    }
+    println!("{:?}", popped); // [10, 20, 30, 40, 50]
+    println!("{:?}", heap.pop()); // None
+
+    let heapified = MinHeap::from_vec(vec![9, 4, 7, 1, 8, 2, 6, 3, 5]);
+    println!("{:?}", heapified.data); // [1, 3, 2, 4, 8, 7, 6, 9, 5]
+    assert!(heapified.is_valid());
+
+    let mut random = vec![31, 4, 15, 92, 65, 35, 89, 79, 26, 53];
+    heap_sort(&mut random);
+    println!("{:?}", random); // [4, 15, 26, 31, 35, 53, 65, 79, 89, 92]
+
+    let mut reversed: Vec<i32> = (1..=8).rev().collect();
+    heap_sort(&mut reversed);
+    println!("{:?}", reversed); // [1, 2, 3, 4, 5, 6, 7, 8]
+
+    let mut equal = vec![7; 5];
+    heap_sort(&mut equal);
+    println!("{:?}", equal); // [7, 7, 7, 7, 7]
+
+    // Highest priority first
+    let mut tasks = PriorityQueue::new(|a: &Task, b: &Task| a.priority.cmp(&b.priority));
+    for (name, priority) in [("email", 2), ("deploy", 9), ("lunch", 5), ("review", 7)] {
+        tasks.push(Task { name, priority });
+    }
+
+    println!("{:?}", tasks.peek().map(|task| task.name)); // Some("deploy")
+    println!("Length: {}", tasks.len()); // Length: 4
+
+    let mut order = Vec::new();
+    while let Some(task) = tasks.pop() {
+        order.push(task.name);
+    }
+    println!("{:?}", order); // ["deploy", "review", "lunch", "email"]
+
+    // Reversing the comparator turns it into a min-queue
+    let mut shortest = PriorityQueue::new(|a: &&str, b: &&str| b.len().cmp(&a.len()));
+    for word in ["banana", "fig", "cherry", "kiwi"] {
+        shortest.push(word);
+    }
+    println!("{:?} {:?}", shortest.pop(), shortest.pop()); // Some("fig") Some("kiwi")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(heap: &mut MinHeap) -> Vec<i32> {
+        std::iter::from_fn(|| heap.pop()).collect()
+    }
+
+    #[test]
+    fn pop_returns_values_in_ascending_order() {
+        let mut heap = MinHeap::new();
+        for value in [5, 3, 8, 1, 9, 1, 4] {
+            heap.push(value);
+            assert!(heap.is_valid());
+        }
+
+        assert_eq!(heap.peek(), Some(&1));
+        assert_eq!(heap.len(), 7);
+        assert_eq!(drain(&mut heap), vec![1, 1, 3, 4, 5, 8, 9]);
+        assert!(heap.is_empty());
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn from_vec_builds_a_valid_heap() {
+        let mut heap = MinHeap::from_vec((0..100).rev().collect());
+
+        assert!(heap.is_valid());
+        assert_eq!(drain(&mut heap), (0..100).collect::<Vec<_>>());
+        assert!(MinHeap::from_vec(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn heap_sort_sorts_ascending() {
+        let inputs: [&[i32]; 5] = [
+            &[31, 4, 15, 92, 65, 35, 89, 79, 26, 53],
+            &[8, 7, 6, 5, 4, 3, 2, 1],
+            &[7, 7, 7, 7, 7],
+            &[1],
+            &[],
+        ];
+
+        for input in inputs {
+            let mut sorted = input.to_vec();
+            heap_sort(&mut sorted);
+
+            let mut expected = input.to_vec();
+            expected.sort();
+            assert_eq!(sorted, expected);
+        }
+    }
+
+    #[test]
+    fn heap_sort_handles_negatives_and_duplicates() {
+        let mut data = vec![3, -1, 3, 0, -5, 2, -1];
+        heap_sort(&mut data);
+        assert_eq!(data, vec![-5, -1, -1, 0, 2, 3, 3]);
+    }
+
+    #[test]
+    fn priority_queue_pops_the_greatest_first() {
+        let mut queue = PriorityQueue::new(|a: &i32, b: &i32| a.cmp(b));
+        for value in [31, 4, 15, 92, 65, 35, 89, 79, 26, 53, 15] {
+            queue.push(value);
+        }
+        assert_eq!(queue.peek(), Some(&92));
+        assert_eq!(queue.len(), 11);
+
+        let mut popped = Vec::new();
+        while let Some(value) = queue.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, vec![92, 89, 79, 65, 53, 35, 31, 26, 15, 15, 4]);
+        assert_eq!(queue.pop(), None);
+        assert_eq!(queue.peek(), None);
+    }
+
+    #[test]
+    fn reversed_comparator_gives_a_min_queue() {
+        let mut queue = PriorityQueue::new(|a: &i32, b: &i32| b.cmp(a));
+        let input: Vec<i32> = (0..200).map(|i| i * 7919 % 200).collect();
+        for &value in &input {
+            queue.push(value);
+        }
+
+        let popped: Vec<i32> = std::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(popped, (0..200).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn priority_queue_orders_by_the_comparator_key() {
+        let mut tasks = PriorityQueue::new(|a: &Task, b: &Task| a.priority.cmp(&b.priority));
+        for (name, priority) in [("email", 2), ("deploy", 9), ("lunch", 5), ("review", 7)] {
+            tasks.push(Task { name, priority });
+        }
+
+        let order: Vec<&str> = std::iter::from_fn(|| tasks.pop())
+            .map(|task| task.name)
+            .collect();
+        assert_eq!(order, ["deploy", "review", "lunch", "email"]);
+    }
+
+    #[test]
+    fn priority_queue_interleaves_pushes_and_pops() {
+        let mut queue = PriorityQueue::new(|a: &i32, b: &i32| a.cmp(b));
+        queue.push(5);
+        queue.push(1);
+        assert_eq!(queue.pop(), Some(5));
+        queue.push(3);
+        queue.push(8);
+        assert_eq!(queue.pop(), Some(8));
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.pop(), None);
+    }
+}
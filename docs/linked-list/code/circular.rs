@@ -0,0 +1,225 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type NodeRef = Rc<RefCell<Node>>;
+
+#[allow(dead_code)]
+struct Node {
+    data: i32,
+    next: Option<NodeRef>,
+}
+
+impl Node {
+    fn new(data: i32) -> NodeRef {
+        Rc::new(RefCell::new(Node { data, next: None }))
+    }
+}
+
+// Only the tail is stored: the head is always tail.next, so both ends are one step away.
+// A one-node list points at itself.
+#[allow(dead_code)]
+struct List {
+    tail: Option<NodeRef>,
+    len: usize,
+}
+
+#[allow(dead_code)]
+impl List {
+    fn new() -> Self {
+        List { tail: None, len: 0 }
+    }
+
+    fn push_back(&mut self, data: i32) {
+        let new_node = Node::new(data);
+
+        match self.tail.take() {
+            None => {
+                new_node.borrow_mut().next = Some(new_node.clone());
+            }
+            Some(old_tail) => {
+                // The new node goes between the old tail and the head
+                let head = old_tail.borrow_mut().next.take();
+                new_node.borrow_mut().next = head;
+                old_tail.borrow_mut().next = Some(new_node.clone());
+            }
+        }
+
+        self.tail = Some(new_node);
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) -> Option<i32> {
+        let tail = self.tail.as_ref()?;
+        let head = tail.borrow().next.clone().unwrap();
+
+        if Rc::ptr_eq(&head, tail) {
+            // Last node: break its self-loop so it can be freed
+            head.borrow_mut().next = None;
+            self.tail = None;
+        } else {
+            let next = head.borrow_mut().next.take();
+            tail.borrow_mut().next = next;
+        }
+
+        self.len -= 1;
+
+        let data = head.borrow().data;
+        Some(data)
+    }
+
+    // Moves the head to the back, turning the whole ring by one step
+    fn advance(&mut self) {
+        if let Some(tail) = self.tail.take() {
+            self.tail = tail.borrow().next.clone();
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    // There is no NONE to stop at, so walk a fixed number of nodes
+    fn print_n(&self, count: usize) {
+        let Some(tail) = &self.tail else {
+            println!("HEAD -> NONE");
+            return;
+        };
+
+        let mut current = tail.borrow().next.clone().unwrap();
+
+        print!("HEAD -> ");
+
+        for _ in 0..count {
+            print!("{} -> ", current.borrow().data);
+
+            let next = current.borrow().next.clone().unwrap();
+            current = next;
+        }
+
+        println!("...");
+    }
+}
+
+// The ring is a reference cycle, so Rc alone would never free it.
+// Cutting the tail's link turns it back into a chain that can be dropped one node at a time.
+impl Drop for List {
+    fn drop(&mut self) {
+        let mut current = self
+            .tail
+            .take()
+            .and_then(|tail| tail.borrow_mut().next.take());
+
+        while let Some(node) = current {
+            current = node.borrow_mut().next.take();
+        }
+    }
+}
+
+// n people stand in a circle and every k-th one is eliminated until one remains.
+// Returns the survivor's 1-based position.
+fn josephus(n: i32, k: i32) -> i32 {
+    assert!(n >= 1 && k >= 1, "josephus needs n >= 1 and k >= 1");
+
+    let mut circle = List::new();
+    for person in 1..=n {
+        circle.push_back(person);
+    }
+
+    while circle.len() > 1 {
+        // Skip k - 1 people, then the one at the front is eliminated
+        for _ in 1..k {
+            circle.advance();
+        }
+        circle.pop_front();
+    }
+
+    circle.pop_front().unwrap()
+}
+
+fn main() {
+    let mut list = List::new();
+
+    list.push_back(10); // HEAD -> [10] -> (back to 10)
+    list.push_back(20); // HEAD -> [10] -> [20] -> (back to 10)
+    list.push_back(30); // HEAD -> [10] -> [20] -> [30] -> (back to 10)
+
+    list.print_n(3); // HEAD -> 10 -> 20 -> 30 -> ...
+    list.print_n(7); // HEAD -> 10 -> 20 -> 30 -> 10 -> 20 -> 30 -> 10 -> ...
+
+    println!("{:?}", list.pop_front()); // Some(10)
+    list.print_n(4); // HEAD -> 20 -> 30 -> 20 -> 30 -> ...
+
+    list.advance();
+    list.print_n(2); // HEAD -> 30 -> 20 -> ...
+
+    println!("{:?}", list.pop_front()); // Some(30)
+    println!("{:?}", list.pop_front()); // Some(20)
+    println!("{:?}", list.pop_front()); // None
+    list.print_n(3); // HEAD -> NONE
+
+    println!("{}", josephus(7, 3)); // 4
+    println!("{}", josephus(5, 2)); // 3
+    println!("{}", josephus(41, 3)); // 31
+    println!("{}", josephus(6, 1)); // 6
+    println!("{}", josephus(1, 5)); // 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(list: &mut List) -> Vec<i32> {
+        std::iter::from_fn(|| list.pop_front()).collect()
+    }
+
+    #[test]
+    fn pop_front_empties_the_ring_in_order() {
+        let mut list = List::new();
+        for data in [10, 20, 30] {
+            list.push_back(data);
+        }
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(drain(&mut list), vec![10, 20, 30]);
+        assert!(list.tail.is_none());
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn advance_turns_the_ring_by_one() {
+        let mut list = List::new();
+        for data in [1, 2, 3] {
+            list.push_back(data);
+        }
+
+        list.advance();
+        list.push_back(4); // Lands behind 1, which is now the tail
+        assert_eq!(drain(&mut list), vec![2, 3, 1, 4]);
+    }
+
+    #[test]
+    fn josephus_matches_the_closed_form() {
+        // J(1) = 0 and J(n) = (J(n - 1) + k) % n, counted from 0
+        for k in 1..6 {
+            let mut survivor = 0;
+            for n in 1..=30 {
+                if n > 1 {
+                    survivor = (survivor + k) % n;
+                }
+                assert_eq!(josephus(n, k), survivor + 1, "n = {}, k = {}", n, k);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "josephus needs n >= 1 and k >= 1")]
+    fn josephus_rejects_k_of_zero() {
+        josephus(5, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "josephus needs n >= 1 and k >= 1")]
+    fn josephus_rejects_an_empty_circle() {
+        josephus(0, 3);
+    }
+}
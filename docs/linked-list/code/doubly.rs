@@ -0,0 +1,327 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+type NodeRef = Rc<RefCell<Node>>;
+
+#[allow(dead_code)]
+struct Node {
+    data: i32,
+    next: Option<NodeRef>,
+    prev: Option<Weak<RefCell<Node>>>,
+}
+
+impl Node {
+    fn new(data: i32) -> NodeRef {
+        Rc::new(RefCell::new(Node {
+            data,
+            next: None,
+            prev: None,
+        }))
+    }
+}
+
+// `next` links own the node after them, `prev` links are Weak so the two
+// directions never form a reference cycle that would keep the nodes alive.
+#[allow(dead_code)]
+struct List {
+    head: Option<NodeRef>,
+    tail: Option<NodeRef>,
+}
+
+#[allow(dead_code)]
+impl List {
+    fn new() -> Self {
+        List {
+            head: None,
+            tail: None,
+        }
+    }
+
+    fn push_front(&mut self, data: i32) {
+        let new_node = Node::new(data);
+
+        match self.head.take() {
+            None => {
+                self.tail = Some(new_node.clone());
+            }
+            Some(old_head) => {
+                old_head.borrow_mut().prev = Some(Rc::downgrade(&new_node));
+                new_node.borrow_mut().next = Some(old_head);
+            }
+        }
+
+        self.head = Some(new_node);
+    }
+
+    fn push_back(&mut self, data: i32) {
+        let new_node = Node::new(data);
+
+        match self.tail.take() {
+            None => {
+                self.head = Some(new_node.clone());
+            }
+            Some(old_tail) => {
+                new_node.borrow_mut().prev = Some(Rc::downgrade(&old_tail));
+                old_tail.borrow_mut().next = Some(new_node.clone());
+            }
+        }
+
+        self.tail = Some(new_node);
+    }
+
+    fn pop_front(&mut self) -> Option<i32> {
+        let old_head = self.head.take()?;
+
+        match old_head.borrow_mut().next.take() {
+            None => {
+                self.tail = None;
+            }
+            Some(next) => {
+                next.borrow_mut().prev = None;
+                self.head = Some(next);
+            }
+        }
+
+        let data = old_head.borrow().data;
+        Some(data)
+    }
+
+    fn pop_back(&mut self) -> Option<i32> {
+        let old_tail = self.tail.take()?;
+
+        let prev = old_tail
+            .borrow_mut()
+            .prev
+            .take()
+            .and_then(|prev| prev.upgrade());
+
+        match prev {
+            None => {
+                self.head = None;
+            }
+            Some(prev) => {
+                prev.borrow_mut().next = None;
+                self.tail = Some(prev);
+            }
+        }
+
+        let data = old_tail.borrow().data;
+        Some(data)
+    }
+
+    // Splices a new node in right after `target`, which must be a node of this list.
+    // Only the neighbors' links change, so it's O(1) with no walking.
+    fn insert_after(&mut self, target: &NodeRef, data: i32) {
+        let new_node = Node::new(data);
+
+        match target.borrow_mut().next.take() {
+            None => {
+                self.tail = Some(new_node.clone());
+            }
+            Some(next) => {
+                next.borrow_mut().prev = Some(Rc::downgrade(&new_node));
+                new_node.borrow_mut().next = Some(next);
+            }
+        }
+
+        new_node.borrow_mut().prev = Some(Rc::downgrade(target));
+        target.borrow_mut().next = Some(new_node);
+    }
+
+    // Inserting before a node is inserting after the one before it, or at the front
+    // if `target` is the head
+    fn insert_before(&mut self, target: &NodeRef, data: i32) {
+        let prev = target
+            .borrow()
+            .prev
+            .as_ref()
+            .and_then(|prev| prev.upgrade());
+
+        match prev {
+            None => self.push_front(data),
+            Some(prev) => self.insert_after(&prev, data),
+        }
+    }
+
+    fn print_forward(&self) {
+        let mut current = self.head.clone();
+
+        print!("HEAD <-> ");
+
+        while let Some(node) = current {
+            print!("{} <-> ", node.borrow().data);
+            current = node.borrow().next.clone();
+        }
+
+        println!("NONE");
+    }
+
+    fn print_backward(&self) {
+        let mut current = self.tail.clone();
+
+        print!("TAIL <-> ");
+
+        while let Some(node) = current {
+            print!("{} <-> ", node.borrow().data);
+            current = node.borrow().prev.as_ref().and_then(|prev| prev.upgrade());
+        }
+
+        println!("NONE");
+    }
+}
+
+fn main() {
+    let mut list = List::new();
+
+    list.push_front(20); // HEAD <-> [20] <-> NONE
+    list.push_front(10); // HEAD <-> [10] <-> [20] <-> NONE
+    list.push_back(30); // HEAD <-> [10] <-> [20] <-> [30] <-> NONE
+    list.push_back(40); // HEAD <-> [10] <-> [20] <-> [30] <-> [40] <-> NONE
+
+    list.print_forward(); // HEAD <-> 10 <-> 20 <-> 30 <-> 40 <-> NONE
+    list.print_backward(); // TAIL <-> 40 <-> 30 <-> 20 <-> 10 <-> NONE
+
+    println!("{:?}", list.pop_front()); // Some(10)
+    println!("{:?}", list.pop_back()); // Some(40)
+
+    list.print_forward(); // HEAD <-> 20 <-> 30 <-> NONE
+    list.print_backward(); // TAIL <-> 30 <-> 20 <-> NONE
+
+    println!("{:?}", list.pop_back()); // Some(30)
+    println!("{:?}", list.pop_back()); // Some(20)
+    println!("{:?}", list.pop_front()); // None
+
+    list.print_forward(); // HEAD <-> NONE
+    list.print_backward(); // TAIL <-> NONE
+
+    list.push_back(20);
+    list.push_back(40);
+
+    let head = list.head.clone().unwrap();
+    let tail = list.tail.clone().unwrap();
+
+    list.insert_after(&head, 30); // Between 20 and 40
+    list.insert_before(&head, 10); // Before the head, so it becomes the new head
+    list.insert_after(&tail, 50); // After the tail, so it becomes the new tail
+
+    list.print_forward(); // HEAD <-> 10 <-> 20 <-> 30 <-> 40 <-> 50 <-> NONE
+    list.print_backward(); // TAIL <-> 50 <-> 40 <-> 30 <-> 20 <-> 10 <-> NONE
+
+    // On both sides of 30, which now sits between two middle nodes
+    let thirty = head.borrow().next.clone().unwrap();
+    list.insert_after(&thirty, 35);
+    list.insert_before(&thirty, 25);
+
+    list.print_forward(); // HEAD <-> 10 <-> 20 <-> 25 <-> 30 <-> 35 <-> 40 <-> 50 <-> NONE
+    list.print_backward(); // TAIL <-> 50 <-> 40 <-> 35 <-> 30 <-> 25 <-> 20 <-> 10 <-> NONE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forward(list: &List) -> Vec<i32> {
+        let mut values = Vec::new();
+        let mut current = list.head.clone();
+
+        while let Some(node) = current {
+            values.push(node.borrow().data);
+            current = node.borrow().next.clone();
+        }
+
+        values
+    }
+
+    fn backward(list: &List) -> Vec<i32> {
+        let mut values = Vec::new();
+        let mut current = list.tail.clone();
+
+        while let Some(node) = current {
+            values.push(node.borrow().data);
+            current = node.borrow().prev.as_ref().and_then(|prev| prev.upgrade());
+        }
+
+        values
+    }
+
+    // Walking back from the tail has to visit the same nodes as walking forward
+    fn assert_consistent(list: &List, expected: &[i32]) {
+        let mut reversed = expected.to_vec();
+        reversed.reverse();
+
+        assert_eq!(forward(list), expected);
+        assert_eq!(backward(list), reversed);
+    }
+
+    #[test]
+    fn back_links_survive_removals() {
+        let mut list = List::new();
+        for data in [2, 3, 4] {
+            list.push_back(data);
+        }
+        list.push_front(1);
+        assert_consistent(&list, &[1, 2, 3, 4]);
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_consistent(&list, &[2, 3, 4]);
+
+        assert_eq!(list.pop_back(), Some(4));
+        assert_consistent(&list, &[2, 3]);
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_consistent(&list, &[2]);
+
+        assert_eq!(list.pop_front(), Some(2));
+        assert_consistent(&list, &[]);
+        assert!(list.head.is_none() && list.tail.is_none());
+
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        // Still usable after being emptied
+        list.push_back(5);
+        list.push_front(4);
+        assert_consistent(&list, &[4, 5]);
+    }
+
+    #[test]
+    fn insert_after_and_before_keep_both_directions_linked() {
+        let mut list = List::new();
+        list.push_back(20);
+        list.push_back(40);
+
+        let head = list.head.clone().unwrap();
+        let tail = list.tail.clone().unwrap();
+
+        list.insert_after(&head, 30);
+        assert_consistent(&list, &[20, 30, 40]);
+
+        let thirty = head.borrow().next.clone().unwrap();
+        list.insert_after(&thirty, 35);
+        list.insert_before(&thirty, 25);
+        assert_consistent(&list, &[20, 25, 30, 35, 40]);
+
+        list.insert_before(&tail, 38);
+        assert_consistent(&list, &[20, 25, 30, 35, 38, 40]);
+    }
+
+    #[test]
+    fn inserting_at_either_end_moves_head_and_tail() {
+        let mut list = List::new();
+        list.push_back(2);
+        let only = list.head.clone().unwrap();
+
+        list.insert_before(&only, 1);
+        list.insert_after(&only, 3);
+        assert_consistent(&list, &[1, 2, 3]);
+        assert_eq!(list.head.as_ref().unwrap().borrow().data, 1);
+        assert_eq!(list.tail.as_ref().unwrap().borrow().data, 3);
+
+        // Both ends can still be popped after being moved by an insert
+        let three = list.tail.clone().unwrap();
+        list.insert_after(&three, 4);
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_consistent(&list, &[2, 3]);
+    }
+}
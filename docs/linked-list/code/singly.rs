@@ -1,16 +1,45 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::ops::{Add, Index, IndexMut};
+use std::ptr;
+use std::rc::Rc;
+
 #[allow(dead_code)]
-struct Node {
-    data: i32,
-    next: Option<Box<Node>>,
+struct Node<T> {
+    data: T,
+    next: Option<Box<Node<T>>>,
+}
+
+// Two lists produced by splitting one
+type Halves<T> = (Option<Box<Node<T>>>, Option<Box<Node<T>>>);
+
+#[derive(Debug, PartialEq)]
+enum ListError {
+    IndexOutOfBounds { index: usize, len: usize },
+    InvalidRange { left: usize, right: usize },
+}
+
+impl fmt::Display for ListError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ListError::IndexOutOfBounds { index, len } => {
+                write!(f, "Index {} out of bounds for length {}", index, len)
+            }
+            ListError::InvalidRange { left, right } => {
+                write!(f, "Range start {} is after range end {}", left, right)
+            }
+        }
+    }
 }
 
 #[allow(dead_code)]
-impl Node {
-    fn new(data: i32) -> Box<Node> {
+impl<T> Node<T> {
+    fn new(data: T) -> Box<Node<T>> {
         Box::new(Node { data, next: None })
     }
 
-    fn get(head: &Option<Box<Node>>, index: usize) -> Option<&Node> {
+    fn get(head: &Option<Box<Node<T>>>, index: usize) -> Option<&Node<T>> {
         let mut current = head;
         let mut i = 0;
 
@@ -26,27 +55,56 @@ impl Node {
         None
     }
 
-    fn print_list(head: &Option<Box<Node>>) {
-        let mut current = head;
+    // Like get, for editing one node in place. None if index is past the end.
+    fn get_mut(head: &mut Option<Box<Node<T>>>, index: usize) -> Option<&mut Node<T>> {
+        let mut current = head.as_deref_mut();
 
-        print!("HEAD -> ");
+        for _ in 0..index {
+            current = current?.next.as_deref_mut();
+        }
 
-        while let Some(node) = current {
-            print!("{}", node.data);
+        current
+    }
 
-            if node.next.is_some() {
-                print!(" -> ");
-            }
+    fn len(head: &Option<Box<Node<T>>>) -> usize {
+        let mut current = head;
+        let mut count = 0;
 
+        while let Some(node) = current {
+            count += 1;
             current = &node.next;
         }
 
-        println!(" -> NONE");
+        count
+    }
+
+    fn first(head: &Option<Box<Node<T>>>) -> Option<&T> {
+        head.as_ref().map(|node| &node.data)
+    }
+
+    // There's no tail pointer, so this walks the whole list
+    fn last(head: &Option<Box<Node<T>>>) -> Option<&T> {
+        Node::iter(head).last()
+    }
+
+    fn iter(head: &Option<Box<Node<T>>>) -> Iter<'_, T> {
+        Iter { current: head }
+    }
+
+    fn into_iter(head: Option<Box<Node<T>>>) -> IntoIter<T> {
+        IntoIter { next: head }
+    }
+
+    fn print_list(head: &Option<Box<Node<T>>>)
+    where
+        T: Display,
+    {
+        println!("{}", ListView(head));
     }
 
-    fn traverse_apply<F>(head: &mut Option<Box<Node>>, mut func: F)
+    fn traverse_apply<F>(head: &mut Option<Box<Node<T>>>, mut func: F)
     where
-        F: FnMut(&mut i32),
+        F: FnMut(&mut T),
     {
         let mut current = head;
 
@@ -57,11 +115,11 @@ impl Node {
         }
     }
 
-    fn insert_at_head(head: Option<Box<Node>>, data: i32) -> Option<Box<Node>> {
+    fn insert_at_head(head: Option<Box<Node<T>>>, data: T) -> Option<Box<Node<T>>> {
         Some(Box::new(Node { data, next: head }))
     }
 
-    fn insert_at_tail(head: Option<Box<Node>>, data: i32) -> Option<Box<Node>> {
+    fn insert_at_tail(head: Option<Box<Node<T>>>, data: T) -> Option<Box<Node<T>>> {
         match head {
             None => Some(Node::new(data)),
             Some(mut node) => {
@@ -78,28 +136,61 @@ impl Node {
         }
     }
 
-    fn insert_at_index(head: Option<Box<Node>>, data: i32, index: usize) -> Option<Box<Node>> {
+    // In-place versions of insert_at_head and insert_at_tail, for building a list in a
+    // loop without reassigning the head every time
+    fn push_front(head: &mut Option<Box<Node<T>>>, data: T) {
+        *head = Node::insert_at_head(head.take(), data);
+    }
+
+    fn push_back(head: &mut Option<Box<Node<T>>>, data: T) {
+        let mut tail = head;
+        while tail.is_some() {
+            tail = &mut tail.as_mut().unwrap().next;
+        }
+
+        *tail = Some(Node::new(data));
+    }
+
+    // Unlike delete_at_head and delete_at_tail, these hand back the removed data and
+    // report an empty list as None instead of printing
+    fn pop_front(head: &mut Option<Box<Node<T>>>) -> Option<T> {
+        let node = head.take()?;
+        *head = node.next;
+
+        Some(node.data)
+    }
+
+    fn pop_back(head: &mut Option<Box<Node<T>>>) -> Option<T> {
+        let mut tail = head;
+        while tail.as_ref()?.next.is_some() {
+            tail = &mut tail.as_mut().unwrap().next;
+        }
+
+        tail.take().map(|node| node.data)
+    }
+
+    fn insert_at_index(
+        head: Option<Box<Node<T>>>,
+        data: T,
+        index: usize,
+    ) -> Result<Option<Box<Node<T>>>, ListError> {
+        let len = Node::len(&head);
+        if index > len {
+            return Err(ListError::IndexOutOfBounds { index, len });
+        }
+
         match head {
-            None => {
-                if index == 0 {
-                    Some(Node::new(data))
-                } else {
-                    panic!("Index out of bounds");
-                }
-            }
+            None => Ok(Some(Node::new(data))),
             Some(mut node) => {
                 if index == 0 {
-                    return Some(Box::new(Node {
+                    return Ok(Some(Box::new(Node {
                         data,
                         next: Some(node),
-                    }));
+                    })));
                 }
 
                 let mut current = &mut node;
                 for _ in 0..index - 1 {
-                    if current.next.is_none() {
-                        panic!("Index out of bounds");
-                    }
                     current = current.next.as_mut().unwrap();
                 }
 
@@ -110,12 +201,12 @@ impl Node {
 
                 current.next = Some(new_node);
 
-                Some(node)
+                Ok(Some(node))
             }
         }
     }
 
-    fn delete_at_head(head: Option<Box<Node>>) -> Option<Box<Node>> {
+    fn delete_at_head(head: Option<Box<Node<T>>>) -> Option<Box<Node<T>>> {
         match head {
             None => {
                 println!("List is empty");
@@ -125,7 +216,7 @@ impl Node {
         }
     }
 
-    fn delete_at_tail(head: Option<Box<Node>>) -> Option<Box<Node>> {
+    fn delete_at_tail(head: Option<Box<Node<T>>>) -> Option<Box<Node<T>>> {
         match head {
             None => {
                 println!("List is empty");
@@ -147,38 +238,32 @@ impl Node {
         }
     }
 
-    fn delete_at_index(head: Option<Box<Node>>, index: usize) -> Option<Box<Node>> {
-        match head {
-            None => {
-                println!("List is empty");
-                None
-            }
-            Some(mut node) => {
-                if index == 0 {
-                    return Node::delete_at_head(Some(node));
-                }
+    fn delete_at_index(
+        head: Option<Box<Node<T>>>,
+        index: usize,
+    ) -> Result<Option<Box<Node<T>>>, ListError> {
+        let len = Node::len(&head);
+        if index >= len {
+            return Err(ListError::IndexOutOfBounds { index, len });
+        }
 
-                let mut current = &mut node;
-                for _ in 0..index - 1 {
-                    if current.next.is_none() {
-                        panic!("Index out of bounds");
-                    }
-                    current = current.next.as_mut().unwrap();
-                }
+        let mut node = head.unwrap();
+        if index == 0 {
+            return Ok(Node::delete_at_head(Some(node)));
+        }
 
-                if current.next.is_none() {
-                    panic!("Index out of bounds");
-                }
+        let mut current = &mut node;
+        for _ in 0..index - 1 {
+            current = current.next.as_mut().unwrap();
+        }
 
-                let target = current.next.take();
-                current.next = target.unwrap().next;
+        let target = current.next.take();
+        current.next = target.unwrap().next;
 
-                Some(node)
-            }
-        }
+        Ok(Some(node))
     }
 
-    fn reverse(head: Option<Box<Node>>) -> Option<Box<Node>> {
+    fn reverse(head: Option<Box<Node<T>>>) -> Option<Box<Node<T>>> {
         let mut prev = None;
         let mut curr = head;
 
@@ -194,7 +279,7 @@ impl Node {
         prev
     }
 
-    fn has_cycle(head: &Option<Box<Node>>) -> bool {
+    fn has_cycle(head: &Option<Box<Node<T>>>) -> bool {
         if head.is_none() {
             return false;
         }
@@ -216,34 +301,2329 @@ impl Node {
 
         false
     }
-}
 
-fn main() {
-    let mut head = None;
+    fn cycle_start(head: &Option<Box<Node<T>>>) -> Option<usize> {
+        let mut slow = head.as_ref();
+        let mut fast = head.as_ref();
+        let mut meeting = None;
 
-    head = Node::insert_at_head(head, 10); // HEAD -> [10] -> NONE
-    head = Node::insert_at_head(head, 20); // HEAD -> [20] -> [10] -> NONE
-    head = Node::insert_at_head(head, 30); // HEAD -> [30] -> [20] -> [10] -> NONE
+        while fast.is_some() && fast.unwrap().next.is_some() {
+            slow = slow.unwrap().next.as_ref();
+            fast = fast.unwrap().next.as_ref().unwrap().next.as_ref();
 
-    Node::print_list(&head);
+            if let (Some(s), Some(f)) = (slow, fast) {
+                if std::ptr::eq(s.as_ref(), f.as_ref()) {
+                    meeting = Some(s);
+                    break;
+                }
+            }
+        }
 
-    head = Node::insert_at_tail(head, 40); // HEAD -> [30] -> [20] -> [10] -> [40] -> NONE
-    Node::print_list(&head);
+        let mut meet = meeting?;
+        let mut start = head.as_ref().unwrap();
+        let mut index = 0;
 
-    head = Node::insert_at_index(head, 50, 2); // HEAD -> [30] -> [20] -> [50] -> [10] -> [40] -> NONE
-    Node::print_list(&head);
+        while !std::ptr::eq(start.as_ref(), meet.as_ref()) {
+            start = start.next.as_ref().unwrap();
+            meet = meet.next.as_ref().unwrap();
+            index += 1;
+        }
 
-    head = Node::delete_at_head(head); // HEAD -> [50] -> [20] -> [10] -> [40] -> NONE
-    Node::print_list(&head);
+        Some(index)
+    }
 
-    head = Node::delete_at_tail(head); // HEAD -> [50] -> [20] -> [10] -> NONE
-    Node::print_list(&head);
+    // For an even length, this returns the second of the two middle nodes
+    fn find_middle(head: &Option<Box<Node<T>>>) -> Option<&Node<T>> {
+        Node::middle(head).1
+    }
 
-    head = Node::delete_at_index(head, 1); // HEAD -> [50] -> [10] -> NONE
-    Node::print_list(&head);
+    // Fast moves two steps for every slow step, so slow is halfway when fast runs out.
+    // Also counts slow's steps, which is the middle node's index.
+    fn middle(head: &Option<Box<Node<T>>>) -> (usize, Option<&Node<T>>) {
+        let mut slow = head.as_ref();
+        let mut fast = head.as_ref();
+        let mut index = 0;
 
-    head = Node::reverse(head); // HEAD -> [10] -> [50] -> NONE
-    Node::print_list(&head);
+        while fast.is_some() && fast.unwrap().next.is_some() {
+            slow = slow.unwrap().next.as_ref();
+            fast = fast.unwrap().next.as_ref().unwrap().next.as_ref();
+            index += 1;
+        }
 
-    println!("Has cycle: {}", Node::has_cycle(&head)); // Has cycle: false
+        (index, slow.map(|node| node.as_ref()))
+    }
+
+    // n = 0 is the last node
+    fn nth_from_end(head: &Option<Box<Node<T>>>, n: usize) -> Option<&Node<T>> {
+        let mut lead = head.as_ref();
+        for _ in 0..=n {
+            lead = lead?.next.as_ref();
+        }
+
+        let mut trail = head.as_ref();
+        while let Some(node) = lead {
+            lead = node.next.as_ref();
+            trail = trail.unwrap().next.as_ref();
+        }
+
+        trail.map(|node| node.as_ref())
+    }
+
+    // Both merge helpers order by Ord through the comparator versions below, so
+    // there is only one merge sort to get right
+    fn merge_sorted(a: Option<Box<Node<T>>>, b: Option<Box<Node<T>>>) -> Option<Box<Node<T>>>
+    where
+        T: Ord,
+    {
+        Node::merge_sorted_by(a, b, &|x: &T, y: &T| x.cmp(y))
+    }
+
+    fn merge_sort(head: Option<Box<Node<T>>>) -> Option<Box<Node<T>>>
+    where
+        T: Ord,
+    {
+        Node::merge_sort_by(head, &|a: &T, b: &T| a.cmp(b))
+    }
+
+    fn sort(head: Option<Box<Node<T>>>) -> Option<Box<Node<T>>>
+    where
+        T: Ord,
+    {
+        Node::merge_sort(head)
+    }
+
+    // Ordered by compare instead of Ord. Equal elements keep their original order.
+    fn sort_by<F>(head: Option<Box<Node<T>>>, compare: F) -> Option<Box<Node<T>>>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        Node::merge_sort_by(head, &compare)
+    }
+
+    fn merge_sort_by<F>(head: Option<Box<Node<T>>>, compare: &F) -> Option<Box<Node<T>>>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        if head.as_ref().is_none_or(|node| node.next.is_none()) {
+            return head;
+        }
+
+        // The middle node starts the back half. With at least two nodes its index is
+        // at least 1, so neither half is empty.
+        let (mid, _) = Node::middle(&head);
+        let (front, back) = Node::split_at(head, mid);
+        Node::merge_sorted_by(
+            Node::merge_sort_by(front, compare),
+            Node::merge_sort_by(back, compare),
+            compare,
+        )
+    }
+
+    fn merge_sorted_by<F>(
+        mut a: Option<Box<Node<T>>>,
+        mut b: Option<Box<Node<T>>>,
+        compare: &F,
+    ) -> Option<Box<Node<T>>>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let mut head = None;
+        let mut tail = &mut head;
+
+        while let (Some(x), Some(y)) = (&a, &b) {
+            // Taking from a on ties is what keeps the sort stable
+            let next = if compare(&x.data, &y.data) != Ordering::Greater {
+                &mut a
+            } else {
+                &mut b
+            };
+
+            let mut node = next.take().unwrap();
+            *next = node.next.take();
+
+            tail = &mut tail.insert(node).next;
+        }
+
+        *tail = if a.is_some() { a } else { b };
+
+        head
+    }
+
+    fn remove_duplicates(mut head: Option<Box<Node<T>>>) -> Option<Box<Node<T>>>
+    where
+        T: PartialEq,
+    {
+        let mut current = head.as_mut();
+
+        while let Some(node) = current {
+            while matches!(&node.next, Some(next) if next.data == node.data) {
+                let duplicate = node.next.take().unwrap();
+                node.next = duplicate.next;
+            }
+
+            current = node.next.as_mut();
+        }
+
+        head
+    }
+
+    // Removes adjacent repeats only, like Vec::dedup, so 1 1 2 1 1 becomes 1 2 1.
+    // That is exactly what remove_duplicates does; sorting just guarantees every
+    // copy of a value is adjacent, which is what lets it remove all of them.
+    fn dedup(head: Option<Box<Node<T>>>) -> Option<Box<Node<T>>>
+    where
+        T: PartialEq,
+    {
+        Node::remove_duplicates(head)
+    }
+
+    // Splits the list into (matching, rest), keeping the original order in both
+    fn partition<F>(head: Option<Box<Node<T>>>, pred: F) -> Halves<T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut matching = None;
+        let mut rest = None;
+        let mut matching_tail = &mut matching;
+        let mut rest_tail = &mut rest;
+        let mut current = head;
+
+        while let Some(mut node) = current {
+            current = node.next.take();
+
+            if pred(&node.data) {
+                matching_tail = &mut matching_tail.insert(node).next;
+            } else {
+                rest_tail = &mut rest_tail.insert(node).next;
+            }
+        }
+
+        (matching, rest)
+    }
+
+    // Relinks each pair so 1 -> 2 -> 3 -> 4 becomes 2 -> 1 -> 4 -> 3, without touching data
+    fn swap_pairs(head: Option<Box<Node<T>>>) -> Option<Box<Node<T>>> {
+        let mut result = None;
+        let mut tail = &mut result;
+        let mut current = head;
+
+        while let Some(mut first) = current {
+            match first.next.take() {
+                None => {
+                    // Odd node out stays where it is
+                    *tail = Some(first);
+                    break;
+                }
+                Some(mut second) => {
+                    current = second.next.take();
+
+                    second.next = Some(first);
+                    tail = &mut tail.insert(second).next.as_mut().unwrap().next;
+                }
+            }
+        }
+
+        result
+    }
+
+    // Rotates right by k, so the last k nodes move to the front
+    fn rotate(mut head: Option<Box<Node<T>>>, k: usize) -> Option<Box<Node<T>>> {
+        let len = Self::len(&head);
+        if len == 0 {
+            return head;
+        }
+
+        let k = k % len;
+        if k == 0 {
+            return head;
+        }
+
+        // Cut the list just before its last k nodes
+        let mut cursor = &mut head;
+        for _ in 0..len - k {
+            cursor = &mut cursor.as_mut().unwrap().next;
+        }
+        let mut rotated = cursor.take();
+
+        // Then hang the old front off the end of the cut-off part
+        let mut tail = &mut rotated;
+        while tail.is_some() {
+            tail = &mut tail.as_mut().unwrap().next;
+        }
+        *tail = head;
+
+        rotated
+    }
+
+    // Reverses the nodes from index left to index right (inclusive), leaving the rest alone
+    fn reverse_between(
+        mut head: Option<Box<Node<T>>>,
+        left: usize,
+        right: usize,
+    ) -> Result<Option<Box<Node<T>>>, ListError> {
+        let len = Node::len(&head);
+        if right >= len {
+            return Err(ListError::IndexOutOfBounds { index: right, len });
+        }
+        if left > right {
+            return Err(ListError::InvalidRange { left, right });
+        }
+
+        // The link that points at the first node of the segment
+        let mut before = &mut head;
+        for _ in 0..left {
+            before = &mut before.as_mut().unwrap().next;
+        }
+
+        let mut rest = before.take();
+        let mut reversed = None;
+        for _ in left..=right {
+            let mut node = rest.unwrap();
+            rest = node.next.take();
+
+            node.next = reversed;
+            reversed = Some(node);
+        }
+
+        // The segment's old first node is now its last, and the rest hangs off it
+        *before = reversed;
+        let mut tail = before;
+        for _ in left..=right {
+            tail = &mut tail.as_mut().unwrap().next;
+        }
+        *tail = rest;
+
+        Ok(head)
+    }
+
+    // Reverses every run of k nodes. A last run shorter than k keeps its order.
+    fn reverse_k_group(head: Option<Box<Node<T>>>, k: usize) -> Option<Box<Node<T>>> {
+        if k <= 1 {
+            return head;
+        }
+
+        let mut result = None;
+        let mut tail = &mut result;
+        let mut rest = head;
+
+        loop {
+            // Only reverse a group if it is complete
+            let mut count = 0;
+            let mut probe = &rest;
+            while let Some(node) = probe {
+                if count == k {
+                    break;
+                }
+                count += 1;
+                probe = &node.next;
+            }
+            if count < k {
+                *tail = rest;
+                break;
+            }
+
+            let mut group = None;
+            for _ in 0..k {
+                let mut node = rest.unwrap();
+                rest = node.next.take();
+
+                node.next = group;
+                group = Some(node);
+            }
+
+            *tail = group;
+            for _ in 0..k {
+                tail = &mut tail.as_mut().unwrap().next;
+            }
+        }
+
+        result
+    }
+
+    // Builds a new list from f applied to every element. The source list is untouched.
+    fn map<U, F>(head: &Option<Box<Node<T>>>, f: F) -> Option<Box<Node<U>>>
+    where
+        F: Fn(&T) -> U,
+    {
+        let mut result = None;
+        let mut tail = &mut result;
+
+        for data in Node::iter(head) {
+            tail = &mut tail.insert(Node::new(f(data))).next;
+        }
+
+        result
+    }
+
+    // Threads an accumulator through every element, front to back
+    fn fold<'a, B, F>(head: &'a Option<Box<Node<T>>>, init: B, f: F) -> B
+    where
+        F: Fn(B, &'a T) -> B,
+    {
+        let mut acc = init;
+
+        for data in Node::iter(head) {
+            acc = f(acc, data);
+        }
+
+        acc
+    }
+
+    fn sum(head: &Option<Box<Node<T>>>) -> T
+    where
+        T: Copy + Default + Add<Output = T>,
+    {
+        Node::fold(head, T::default(), |acc, &x| acc + x)
+    }
+
+    // Same result as sum, written as structural recursion: an empty list sums to zero,
+    // and a node sums to its data plus the sum of the rest. Every node adds a stack
+    // frame that stays until the end is reached, so a long enough list overflows the
+    // stack where the iterative version wouldn't.
+    fn sum_recursive(head: &Option<Box<Node<T>>>) -> T
+    where
+        T: Copy + Default + Add<Output = T>,
+    {
+        match head {
+            None => T::default(),
+            Some(node) => node.data + Node::sum_recursive(&node.next),
+        }
+    }
+
+    // None for an empty list
+    fn max(head: &Option<Box<Node<T>>>) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        Node::fold(head, None, |best, x| match best {
+            Some(b) if b >= x => Some(b),
+            _ => Some(x),
+        })
+    }
+
+    // The first index nodes, then everything after them. No nodes are copied.
+    fn split_at(mut head: Option<Box<Node<T>>>, index: usize) -> Halves<T> {
+        let mut cursor = &mut head;
+        for _ in 0..index {
+            match cursor {
+                Some(node) => cursor = &mut node.next,
+                None => break,
+            }
+        }
+
+        let rest = cursor.take();
+        (head, rest)
+    }
+
+    // Links b onto the end of a. Both are consumed and no nodes are copied.
+    fn concat(mut a: Option<Box<Node<T>>>, b: Option<Box<Node<T>>>) -> Option<Box<Node<T>>> {
+        if a.is_none() {
+            return b;
+        }
+
+        let mut tail = &mut a;
+        while tail.is_some() {
+            tail = &mut tail.as_mut().unwrap().next;
+        }
+        *tail = b;
+
+        a
+    }
+
+    // O(1) extra space: reverse the second half in place, compare it against the first
+    // half, then reverse it back. That's why this needs &mut even though the list
+    // comes back unchanged.
+    fn is_palindrome(head: &mut Option<Box<Node<T>>>) -> bool
+    where
+        T: PartialEq,
+    {
+        let len = Node::len(head);
+        if len < 2 {
+            return true;
+        }
+
+        // For odd lengths the middle node stays with the first half and is never compared
+        let cut = len.div_ceil(2);
+
+        let mut cursor = &mut *head;
+        for _ in 0..cut {
+            cursor = &mut cursor.as_mut().unwrap().next;
+        }
+        let second = Node::reverse(cursor.take());
+
+        let result = Node::iter(head)
+            .zip(Node::iter(&second))
+            .all(|(a, b)| a == b);
+
+        let mut cursor = head;
+        for _ in 0..cut {
+            cursor = &mut cursor.as_mut().unwrap().next;
+        }
+        *cursor = Node::reverse(second);
+
+        result
+    }
+
+    // Index in a of the first node that a and b share. Two separately built Box lists
+    // can never share nodes, so this only finds something when b borrows a sublist of a;
+    // RcNode::intersection_node handles lists that really merge.
+    fn intersection_node(a: &Option<Box<Node<T>>>, b: &Option<Box<Node<T>>>) -> Option<usize> {
+        let len_a = Node::len(a);
+        let len_b = Node::len(b);
+        let mut a = a.as_deref();
+        let mut b = b.as_deref();
+        let mut index = 0;
+
+        // Drop the extra nodes at the front of the longer list, so both are the same
+        // distance from a shared tail
+        for _ in len_b..len_a {
+            a = a?.next.as_deref();
+            index += 1;
+        }
+        for _ in len_a..len_b {
+            b = b?.next.as_deref();
+        }
+
+        while let (Some(x), Some(y)) = (a, b) {
+            if std::ptr::eq(x, y) {
+                return Some(index);
+            }
+
+            a = x.next.as_deref();
+            b = y.next.as_deref();
+            index += 1;
+        }
+
+        None
+    }
+
+    // Keeps only the elements pred accepts, like Vec::retain. Removed nodes are
+    // unlinked in the same single pass.
+    fn retain<F>(mut head: Option<Box<Node<T>>>, pred: F) -> Option<Box<Node<T>>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut cursor = &mut head;
+
+        while cursor.is_some() {
+            if pred(&cursor.as_ref().unwrap().data) {
+                cursor = &mut cursor.as_mut().unwrap().next;
+            } else {
+                // Don't advance: the node that slides into this link needs checking too
+                let removed = cursor.take().unwrap();
+                *cursor = removed.next;
+            }
+        }
+
+        head
+    }
+
+    // Every node equal to value goes, wherever it is, in one pass
+    fn remove_all(head: Option<Box<Node<T>>>, value: &T) -> Option<Box<Node<T>>>
+    where
+        T: PartialEq,
+    {
+        Node::retain(head, |x| x != value)
+    }
+
+    // Nodes at positions 1, 3, 5, ... followed by nodes at 2, 4, 6, ..., each group in
+    // its original order. Like partition, but by position rather than by value.
+    fn odd_even_list(head: Option<Box<Node<T>>>) -> Option<Box<Node<T>>> {
+        let mut odds = None;
+        let mut evens = None;
+        let mut odd_tail = &mut odds;
+        let mut even_tail = &mut evens;
+        let mut current = head;
+        let mut odd = true;
+
+        while let Some(mut node) = current {
+            current = node.next.take();
+
+            if odd {
+                odd_tail = &mut odd_tail.insert(node).next;
+            } else {
+                even_tail = &mut even_tail.insert(node).next;
+            }
+            odd = !odd;
+        }
+
+        *odd_tail = evens;
+        odds
+    }
+
+    // Builds a list in slice order
+    fn from_slice(items: &[T]) -> Option<Box<Node<T>>>
+    where
+        T: Clone,
+    {
+        let mut head = None;
+        let mut tail = &mut head;
+
+        for data in items {
+            tail = &mut tail.insert(Node::new(data.clone())).next;
+        }
+
+        head
+    }
+
+    fn to_vec(head: &Option<Box<Node<T>>>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        Node::iter(head).cloned().collect()
+    }
+
+    // Pairs up elements by position and stops at the end of the shorter list
+    fn zip(a: &Option<Box<Node<T>>>, b: &Option<Box<Node<T>>>) -> Vec<(T, T)>
+    where
+        T: Clone,
+    {
+        Node::iter(a)
+            .zip(Node::iter(b))
+            .map(|(x, y)| (x.clone(), y.clone()))
+            .collect()
+    }
+
+    // The values back to front, separated by spaces. The list itself isn't reversed:
+    // the nodes are pushed on a stack and popped off in the opposite order. Recursing
+    // to the end first would work too, but one frame per node can overflow on long lists.
+    fn reverse_to_string(head: &Option<Box<Node<T>>>) -> String
+    where
+        T: Display,
+    {
+        let stack: Vec<&T> = Node::iter(head).collect();
+
+        stack
+            .iter()
+            .rev()
+            .map(|data| data.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn reverse_print(head: &Option<Box<Node<T>>>)
+    where
+        T: Display,
+    {
+        println!("{}", Node::reverse_to_string(head));
+    }
+
+    fn find<F>(head: &Option<Box<Node<T>>>, pred: F) -> Option<usize>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut current = head;
+        let mut i = 0;
+
+        while let Some(node) = current {
+            if pred(&node.data) {
+                return Some(i);
+            }
+
+            current = &node.next;
+            i += 1;
+        }
+
+        None
+    }
+
+    fn contains(head: &Option<Box<Node<T>>>, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        Node::find(head, |data| data == value).is_some()
+    }
+
+    fn count_occurrences(head: &Option<Box<Node<T>>>, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        Node::iter(head).filter(|&data| data == value).count()
+    }
+}
+
+// Digit lists store a number least significant digit first, so 342 is 2 -> 4 -> 3
+#[allow(dead_code)]
+impl Node<i32> {
+    fn add_two_numbers(
+        a: Option<Box<Node<i32>>>,
+        b: Option<Box<Node<i32>>>,
+    ) -> Option<Box<Node<i32>>> {
+        let mut result = None;
+        let mut tail = &mut result;
+        let mut a = a.as_deref();
+        let mut b = b.as_deref();
+        let mut carry = 0;
+
+        // Keep going while either number has digits left, or a carry is still owed
+        while a.is_some() || b.is_some() || carry > 0 {
+            let mut sum = carry;
+
+            if let Some(node) = a {
+                sum += node.data;
+                a = node.next.as_deref();
+            }
+            if let Some(node) = b {
+                sum += node.data;
+                b = node.next.as_deref();
+            }
+
+            carry = sum / 10;
+            tail = &mut tail.insert(Node::new(sum % 10)).next;
+        }
+
+        result
+    }
+}
+
+type Link<T> = Option<Rc<RefCell<RcNode<T>>>>;
+
+#[allow(dead_code)]
+struct RcNode<T> {
+    data: T,
+    next: Link<T>,
+}
+
+#[allow(dead_code)]
+impl<T> RcNode<T> {
+    fn new(data: T) -> Rc<RefCell<RcNode<T>>> {
+        Rc::new(RefCell::new(RcNode { data, next: None }))
+    }
+
+    fn step(link: &Link<T>) -> Link<T> {
+        link.as_ref().and_then(|node| node.borrow().next.clone())
+    }
+
+    fn len(head: &Link<T>) -> usize {
+        let mut len = 0;
+        let mut current = head.clone();
+
+        while current.is_some() {
+            current = RcNode::step(&current);
+            len += 1;
+        }
+
+        len
+    }
+
+    fn meeting_point(head: &Link<T>) -> Link<T> {
+        let mut slow = head.clone();
+        let mut fast = head.clone();
+
+        while fast.is_some() && RcNode::step(&fast).is_some() {
+            slow = RcNode::step(&slow);
+            fast = RcNode::step(&RcNode::step(&fast));
+
+            if let (Some(s), Some(f)) = (&slow, &fast) {
+                if Rc::ptr_eq(s, f) {
+                    return slow;
+                }
+            }
+        }
+
+        None
+    }
+
+    fn has_cycle(head: &Link<T>) -> bool {
+        RcNode::meeting_point(head).is_some()
+    }
+
+    // Same length alignment as Node::intersection_node, but here both lists can
+    // actually point at the same nodes
+    fn intersection_node(a: &Link<T>, b: &Link<T>) -> Option<usize> {
+        let len_a = RcNode::len(a);
+        let len_b = RcNode::len(b);
+        let mut a = a.clone();
+        let mut b = b.clone();
+        let mut index = 0;
+
+        for _ in len_b..len_a {
+            a = RcNode::step(&a);
+            index += 1;
+        }
+        for _ in len_a..len_b {
+            b = RcNode::step(&b);
+        }
+
+        while let (Some(x), Some(y)) = (&a, &b) {
+            if Rc::ptr_eq(x, y) {
+                return Some(index);
+            }
+
+            a = RcNode::step(&a);
+            b = RcNode::step(&b);
+            index += 1;
+        }
+
+        None
+    }
+
+    fn cycle_start(head: &Link<T>) -> Option<usize> {
+        let mut meet = RcNode::meeting_point(head)?;
+        let mut start = head.clone()?;
+        let mut index = 0;
+
+        while !Rc::ptr_eq(&start, &meet) {
+            start = RcNode::step(&Some(start))?;
+            meet = RcNode::step(&Some(meet))?;
+            index += 1;
+        }
+
+        Some(index)
+    }
+}
+
+// `tail` points at the last node, like in tail.rs, and is null exactly when the list is
+// empty. Reading through `head` is fine, but adding or removing nodes has to go
+// through these methods so `tail` stays in sync.
+struct List<T> {
+    head: Option<Box<Node<T>>>,
+    tail: *mut Node<T>,
+}
+
+#[allow(dead_code)]
+impl<T> List<T> {
+    fn new() -> Self {
+        List {
+            head: None,
+            tail: ptr::null_mut(),
+        }
+    }
+
+    // Walks the nodes once to find the tail
+    fn from_head(head: Option<Box<Node<T>>>) -> Self {
+        let mut list = List::new();
+        list.head = head;
+
+        let mut current = list.head.as_deref_mut();
+        while let Some(node) = current {
+            // Taken last, so nothing reborrows the node after the pointer to it exists
+            if node.next.is_none() {
+                list.tail = node;
+                break;
+            }
+            current = node.next.as_deref_mut();
+        }
+
+        list
+    }
+
+    fn into_head(mut self) -> Option<Box<Node<T>>> {
+        self.tail = ptr::null_mut();
+        self.head.take()
+    }
+
+    fn push_front(&mut self, data: T) {
+        let old_head = self.head.take();
+        let new_node = self.head.insert(Box::new(Node {
+            data,
+            next: old_head,
+        }));
+
+        // The new node is also the last one if the list was empty
+        if self.tail.is_null() {
+            self.tail = &mut **new_node;
+        }
+    }
+
+    // O(1): no walk, the new node goes straight after `tail`
+    fn push_back(&mut self, data: T) {
+        let slot = if self.tail.is_null() {
+            &mut self.head
+        } else {
+            // SAFETY: `tail` is only non-null while it points at the last node owned
+            // by `head`, and `&mut self` means nothing else is borrowing that node.
+            unsafe { &mut (*self.tail).next }
+        };
+
+        self.tail = &mut **slot.insert(Node::new(data));
+    }
+
+    fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        Node::to_vec(&self.head)
+    }
+}
+
+impl<T> From<Vec<T>> for List<T> {
+    fn from(items: Vec<T>) -> Self {
+        items.into_iter().collect()
+    }
+}
+
+// Collects in iteration order
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+// Every new element goes straight after `tail`, so extending by m elements is O(m)
+// however long the list already is, and so is each later call
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push_back(data);
+        }
+    }
+}
+
+// Panics on an out of bounds index, with the same message as Vec
+impl<T> Index<usize> for List<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match Node::get(&self.head, index) {
+            Some(node) => &node.data,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                Node::len(&self.head),
+                index
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for List<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        // Measured up front: the borrow from get_mut would still be live in the None arm
+        let len = Node::len(&self.head);
+
+        match Node::get_mut(&mut self.head, index) {
+            Some(node) => &mut node.data,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                len, index
+            ),
+        }
+    }
+}
+
+// The default drop is recursive: dropping a Box<Node> drops its `next`, which drops
+// its `next`, and so on. Each node adds a stack frame, so a long enough list
+// overflows the stack. Taking each `next` out first lets every node drop on its own.
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+
+        while let Some(mut node) = current {
+            current = node.next.take();
+        }
+    }
+}
+
+// A derived Clone would recurse once per node, like the default drop does.
+// Collecting copies front to back in a loop instead, and sets up the copy's tail.
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        Node::iter(&self.head).cloned().collect()
+    }
+}
+
+// Walks both lists in step, so a length mismatch shows up as one side running out first
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let mut a = &self.head;
+        let mut b = &other.head;
+
+        loop {
+            match (a, b) {
+                (None, None) => return true,
+                (Some(x), Some(y)) if x.data == y.data => {
+                    a = &x.next;
+                    b = &y.next;
+                }
+                _ => return false,
+            }
+        }
+    }
+}
+
+impl<T: Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ListView(&self.head).fmt(f)
+    }
+}
+
+// Lets a bare head be formatted the same way as a List without taking ownership of it
+struct ListView<'a, T>(&'a Option<Box<Node<T>>>);
+
+impl<T: Display> fmt::Display for ListView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut current = self.0;
+
+        write!(f, "HEAD -> ")?;
+
+        while let Some(node) = current {
+            write!(f, "{} -> ", node.data)?;
+
+            current = &node.next;
+        }
+
+        write!(f, "NONE")
+    }
+}
+
+struct Iter<'a, T> {
+    current: &'a Option<Box<Node<T>>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.as_ref().map(|node| {
+            self.current = &node.next;
+            &node.data
+        })
+    }
+}
+
+struct IntoIter<T> {
+    next: Option<Box<Node<T>>>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|mut node| {
+            self.next = node.next.take();
+            node.data
+        })
+    }
+}
+
+fn main() {
+    let mut head = None;
+
+    head = Node::insert_at_head(head, 10); // HEAD -> [10] -> NONE
+    head = Node::insert_at_head(head, 20); // HEAD -> [20] -> [10] -> NONE
+    head = Node::insert_at_head(head, 30); // HEAD -> [30] -> [20] -> [10] -> NONE
+
+    Node::print_list(&head);
+
+    for x in Node::iter(&head) {
+        print!("{} ", x);
+    }
+    println!(); // 30 20 10
+
+    let doubled: Vec<i32> = Node::iter(&head).map(|x| x * 2).collect();
+    println!("{:?}", doubled); // [60, 40, 20]
+
+    let empty: Option<Box<Node<i32>>> = None;
+    println!("Empty count: {}", Node::iter(&empty).count()); // Empty count: 0
+
+    head = Node::insert_at_tail(head, 40); // HEAD -> [30] -> [20] -> [10] -> [40] -> NONE
+    Node::print_list(&head);
+
+    head = Node::insert_at_index(head, 50, 2).unwrap(); // HEAD -> [30] -> [20] -> [50] -> [10] -> [40] -> NONE
+    Node::print_list(&head);
+
+    head = Node::delete_at_head(head); // HEAD -> [50] -> [20] -> [10] -> [40] -> NONE
+    Node::print_list(&head);
+
+    head = Node::delete_at_tail(head); // HEAD -> [50] -> [20] -> [10] -> NONE
+    Node::print_list(&head);
+
+    head = Node::delete_at_index(head, 1).unwrap(); // HEAD -> [50] -> [10] -> NONE
+    Node::print_list(&head);
+
+    head = Node::reverse(head); // HEAD -> [10] -> [50] -> NONE
+    Node::print_list(&head);
+
+    println!("Length: {}", Node::len(&head)); // Length: 2
+
+    let empty: Option<Box<Node<i32>>> = None;
+    println!("Empty length: {}", Node::len(&empty)); // Empty length: 0
+    println!("Single length: {}", Node::len(&Some(Node::new(1)))); // Single length: 1
+
+    if let Err(e) = Node::insert_at_index(empty, 99, 3) {
+        println!("{}", e); // Index 3 out of bounds for length 0
+    }
+
+    let short = Node::insert_at_head(None, 1);
+    println!("{:?}", Node::delete_at_index(short, 5).err()); // Some(IndexOutOfBounds { index: 5, len: 1 })
+
+    println!("Has cycle: {}", Node::has_cycle(&head)); // Has cycle: false
+
+    let drain = List::from(vec![30, 20, 10]).into_head();
+
+    let mut values = Node::into_iter(drain);
+    let drained: Vec<i32> = values.by_ref().collect();
+    println!("{:?}", drained); // [30, 20, 10]
+    assert!(values.next().is_none(), "the list should be fully consumed");
+
+    let mut long = List::new();
+    for i in 0..1_000_000 {
+        long.push_front(i);
+    }
+    println!("Long length: {}", Node::len(&long.head)); // Long length: 1000000
+    drop(long); // No stack overflow
+
+    let mut words = None;
+
+    words = Node::insert_at_tail(words, "world"); // HEAD -> [world] -> NONE
+    words = Node::insert_at_head(words, "hello"); // HEAD -> [hello] -> [world] -> NONE
+    Node::print_list(&words);
+
+    words = Node::reverse(words); // HEAD -> [world] -> [hello] -> NONE
+    Node::print_list(&words);
+
+    let search = List::from(vec![10, 20, 30]).into_head(); // HEAD -> [10] -> [20] -> [30] -> NONE
+
+    println!("{:?}", Node::find(&search, |x| *x == 10)); // Some(0)
+    println!("{:?}", Node::find(&search, |x| *x > 15)); // Some(1)
+    println!("{:?}", Node::find(&search, |x| *x > 100)); // None
+    println!("Contains 30: {}", Node::contains(&search, &30)); // Contains 30: true
+    println!("Contains 40: {}", Node::contains(&search, &40)); // Contains 40: false
+
+    println!("Cycle start: {:?}", Node::cycle_start(&search)); // Cycle start: None
+
+    let nodes: Vec<_> = (1..=4).map(RcNode::new).collect();
+    for pair in nodes.windows(2) {
+        pair[0].borrow_mut().next = Some(pair[1].clone());
+    }
+    let cyclic = Some(nodes[0].clone()); // HEAD -> [1] -> [2] -> [3] -> [4] -> NONE
+
+    println!("Has cycle: {}", RcNode::has_cycle(&cyclic)); // Has cycle: false
+
+    nodes[3].borrow_mut().next = Some(nodes[1].clone()); // [4] -> [2]
+    println!("Has cycle: {}", RcNode::has_cycle(&cyclic)); // Has cycle: true
+    println!("Cycle start: {:?}", RcNode::cycle_start(&cyclic)); // Cycle start: Some(1)
+
+    nodes[3].borrow_mut().next = None; // Break the cycle so the nodes can be freed
+
+    let odd = List::from(vec![1, 2, 3, 4, 5]).into_head(); // HEAD -> [1] -> [2] -> [3] -> [4] -> [5] -> NONE
+    println!("Middle: {}", Node::find_middle(&odd).unwrap().data); // Middle: 3
+
+    let even = Node::delete_at_tail(odd); // HEAD -> [1] -> [2] -> [3] -> [4] -> NONE
+    println!("Middle: {}", Node::find_middle(&even).unwrap().data); // Middle: 3
+
+    let last = Node::nth_from_end(&even, 0).map(|node| node.data);
+    let first = Node::nth_from_end(&even, 3).map(|node| node.data);
+    let past = Node::nth_from_end(&even, 4).map(|node| node.data);
+    println!("{:?} {:?} {:?}", last, first, past); // Some(4) Some(1) None
+
+    let a = List::from(vec![1, 3, 5, 7]).into_head();
+    let b = List::from(vec![2, 4, 6]).into_head();
+
+    let merged = Node::merge_sorted(a, b);
+    Node::print_list(&merged); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7 -> NONE
+
+    let merged = Node::merge_sorted(merged, None);
+    Node::print_list(&merged); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7 -> NONE
+
+    let high = List::from(vec![10, 20, 30]).into_head();
+    let merged = Node::merge_sorted(high, merged);
+    Node::print_list(&merged); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7 -> 10 -> 20 -> 30 -> NONE
+
+    let reversed = List::from(vec![6, 5, 4, 3, 2, 1]).into_head();
+    let sorted = Node::merge_sort(reversed);
+    Node::print_list(&sorted); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> NONE
+
+    let dupes = List::from(vec![2, 1, 3, 2, 1, 3]).into_head();
+    let sorted_dupes = Node::merge_sort(dupes);
+    Node::print_list(&sorted_dupes); // HEAD -> 1 -> 1 -> 2 -> 2 -> 3 -> 3 -> NONE
+
+    let already = Node::merge_sort(sorted);
+    Node::print_list(&already); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> NONE
+
+    let unique = Node::remove_duplicates(sorted_dupes);
+    Node::print_list(&unique); // HEAD -> 1 -> 2 -> 3 -> NONE
+
+    let runs = List::from(vec![1, 1, 2, 3, 3, 3]).into_head();
+    let runs = List::from_head(Node::remove_duplicates(runs));
+    println!("{:?}", runs.to_vec()); // [1, 2, 3]
+
+    let no_dupes = Node::remove_duplicates(already);
+    Node::print_list(&no_dupes); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> NONE
+
+    let nothing: Option<Box<Node<i32>>> = Node::remove_duplicates(None);
+    println!("Empty length: {}", Node::len(&nothing)); // Empty length: 0
+
+    let shown = List::from(vec![30, 20, 10]);
+    let formatted = format!("{}", shown);
+    println!("{}", formatted); // HEAD -> 30 -> 20 -> 10 -> NONE
+    println!("{}", List::<i32>::new()); // HEAD -> NONE
+
+    let numbers = List::from(vec![1, 2, 3, 4, 5, 6, 7]).into_head();
+    let (evens, odds) = Node::partition(numbers, |x| x % 2 == 0);
+    Node::print_list(&evens); // HEAD -> 2 -> 4 -> 6 -> NONE
+    Node::print_list(&odds); // HEAD -> 1 -> 3 -> 5 -> 7 -> NONE
+
+    let pairs = Node::swap_pairs(List::from(vec![1, 2, 3, 4]).into_head());
+    Node::print_list(&pairs); // HEAD -> 2 -> 1 -> 4 -> 3 -> NONE
+
+    let pairs = Node::swap_pairs(List::from(vec![1, 2, 3, 4, 5]).into_head());
+    Node::print_list(&pairs); // HEAD -> 2 -> 1 -> 4 -> 3 -> 5 -> NONE
+
+    let pairs = Node::swap_pairs(List::from(vec![1]).into_head());
+    Node::print_list(&pairs); // HEAD -> 1 -> NONE
+
+    let pairs: Option<Box<Node<i32>>> = Node::swap_pairs(None);
+    Node::print_list(&pairs); // HEAD -> NONE
+
+    let rotated = Node::rotate(List::from(vec![1, 2, 3, 4, 5]).into_head(), 2);
+    Node::print_list(&rotated); // HEAD -> 4 -> 5 -> 1 -> 2 -> 3 -> NONE
+
+    // Rotating by the length is the identity
+    let rotated = Node::rotate(List::from(vec![1, 2, 3, 4, 5]).into_head(), 5);
+    Node::print_list(&rotated); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> NONE
+
+    // 7 % 5 == 2
+    let rotated = Node::rotate(List::from(vec![1, 2, 3, 4, 5]).into_head(), 7);
+    Node::print_list(&rotated); // HEAD -> 4 -> 5 -> 1 -> 2 -> 3 -> NONE
+
+    let rotated: Option<Box<Node<i32>>> = Node::rotate(None, 3);
+    Node::print_list(&rotated); // HEAD -> NONE
+
+    let middle = List::from(vec![1, 2, 3, 4, 5]).into_head();
+    let middle = Node::reverse_between(middle, 1, 3).unwrap();
+    Node::print_list(&middle); // HEAD -> 1 -> 4 -> 3 -> 2 -> 5 -> NONE
+
+    let front = List::from(vec![1, 2, 3, 4, 5]).into_head();
+    let front = Node::reverse_between(front, 0, 2).unwrap();
+    Node::print_list(&front); // HEAD -> 3 -> 2 -> 1 -> 4 -> 5 -> NONE
+
+    let back = List::from(vec![1, 2, 3, 4, 5]).into_head();
+    let back = Node::reverse_between(back, 2, 4).unwrap();
+    Node::print_list(&back); // HEAD -> 1 -> 2 -> 5 -> 4 -> 3 -> NONE
+
+    let short = List::from(vec![1, 2, 3]).into_head();
+    println!("{:?}", Node::reverse_between(short, 1, 3).err()); // Some(IndexOutOfBounds { index: 3, len: 3 })
+
+    let short = List::from(vec![1, 2, 3]).into_head();
+    if let Err(e) = Node::reverse_between(short, 2, 1) {
+        println!("{}", e); // Range start 2 is after range end 1
+    }
+
+    let groups = List::from(vec![1, 2, 3, 4, 5, 6]).into_head();
+    let groups = Node::reverse_k_group(groups, 3);
+    Node::print_list(&groups); // HEAD -> 3 -> 2 -> 1 -> 6 -> 5 -> 4 -> NONE
+
+    // The leftover 5 is shorter than k, so it stays as it is
+    let groups = List::from(vec![1, 2, 3, 4, 5]).into_head();
+    let groups = Node::reverse_k_group(groups, 2);
+    Node::print_list(&groups); // HEAD -> 2 -> 1 -> 4 -> 3 -> 5 -> NONE
+
+    let groups = List::from(vec![1, 2, 3]).into_head();
+    let groups = Node::reverse_k_group(groups, 1);
+    Node::print_list(&groups); // HEAD -> 1 -> 2 -> 3 -> NONE
+
+    let digits = List::from(vec![1, 2, 3]).into_head();
+    let labels = Node::map(&digits, |x| format!("#{}", x));
+    Node::print_list(&labels); // HEAD -> #1 -> #2 -> #3 -> NONE
+    Node::print_list(&digits); // HEAD -> 1 -> 2 -> 3 -> NONE
+
+    let strings: Vec<String> = Node::into_iter(Node::map(&digits, |x| x.to_string())).collect();
+    println!("{:?}", strings); // ["1", "2", "3"]
+
+    let scores = List::from(vec![4, 9, 2, 7]).into_head();
+    println!("Sum: {}", Node::sum(&scores)); // Sum: 22
+    println!("Sum: {}", Node::sum_recursive(&scores)); // Sum: 22
+    println!("Max: {:?}", Node::max(&scores)); // Max: Some(9)
+
+    let joined = Node::fold(&scores, String::new(), |acc, x| acc + &x.to_string());
+    println!("{}", joined); // 4927
+
+    let blank: Option<Box<Node<i32>>> = None;
+    println!("{}", Node::fold(&blank, 100, |acc, x| acc + x)); // 100
+    println!("Max: {:?}", Node::max(&blank)); // Max: None
+
+    let left = List::from(vec![1, 2, 3]);
+    assert!(left == List::from(vec![1, 2, 3]));
+    assert!(left != List::from(vec![1, 2]));
+    assert!(left != List::from(vec![1, 2, 3, 4]));
+    assert!(left != List::from(vec![1, 5, 3]));
+    assert!(List::<i32>::new() == List::new());
+
+    let original = List::from(vec![1, 2, 3]);
+    let mut copy = original.clone();
+    Node::traverse_apply(&mut copy.head, |x| *x *= 10);
+    println!("{}", copy); // HEAD -> 10 -> 20 -> 30 -> NONE
+    println!("{}", original); // HEAD -> 1 -> 2 -> 3 -> NONE
+
+    let big: List<i32> = List::from((0..1_000_000).collect::<Vec<_>>());
+    assert!(big.clone() == big);
+
+    let whole = List::from(vec![1, 2, 3, 4, 5]).into_head();
+    let (front, back) = Node::split_at(whole, 2);
+    Node::print_list(&front); // HEAD -> 1 -> 2 -> NONE
+    Node::print_list(&back); // HEAD -> 3 -> 4 -> 5 -> NONE
+
+    let whole = List::from(vec![1, 2, 3]).into_head();
+    let (front, back) = Node::split_at(whole, 0);
+    Node::print_list(&front); // HEAD -> NONE
+    Node::print_list(&back); // HEAD -> 1 -> 2 -> 3 -> NONE
+
+    let whole = List::from(vec![1, 2, 3]).into_head();
+    let (front, back) = Node::split_at(whole, 10);
+    Node::print_list(&front); // HEAD -> 1 -> 2 -> 3 -> NONE
+    Node::print_list(&back); // HEAD -> NONE
+
+    let first = List::from(vec![1, 2]).into_head();
+    let second = List::from(vec![3, 4]).into_head();
+    let joined = Node::concat(first, second);
+    Node::print_list(&joined); // HEAD -> 1 -> 2 -> 3 -> 4 -> NONE
+
+    let joined = Node::concat(None, List::from(vec![3, 4]).into_head());
+    Node::print_list(&joined); // HEAD -> 3 -> 4 -> NONE
+
+    let joined = Node::concat(List::from(vec![1, 2]).into_head(), None);
+    Node::print_list(&joined); // HEAD -> 1 -> 2 -> NONE
+
+    // 342 + 465 = 807
+    let x = List::from(vec![2, 4, 3]).into_head();
+    let y = List::from(vec![5, 6, 4]).into_head();
+    Node::print_list(&Node::add_two_numbers(x, y)); // HEAD -> 7 -> 0 -> 8 -> NONE
+
+    // 99 + 1 = 100
+    let x = List::from(vec![9, 9]).into_head();
+    let y = List::from(vec![1]).into_head();
+    Node::print_list(&Node::add_two_numbers(x, y)); // HEAD -> 0 -> 0 -> 1 -> NONE
+
+    // 9 + 1 = 10, the final carry adds a node
+    let x = List::from(vec![9]).into_head();
+    let y = List::from(vec![1]).into_head();
+    Node::print_list(&Node::add_two_numbers(x, y)); // HEAD -> 0 -> 1 -> NONE
+
+    let mut odd_palindrome = List::from(vec![1, 2, 3, 2, 1]).into_head();
+    assert!(Node::is_palindrome(&mut odd_palindrome));
+    Node::print_list(&odd_palindrome); // HEAD -> 1 -> 2 -> 3 -> 2 -> 1 -> NONE
+
+    let mut even_palindrome = List::from(vec![1, 2, 2, 1]).into_head();
+    assert!(Node::is_palindrome(&mut even_palindrome));
+
+    let mut not_palindrome = List::from(vec![1, 2, 3, 4]).into_head();
+    assert!(!Node::is_palindrome(&mut not_palindrome));
+    Node::print_list(&not_palindrome); // HEAD -> 1 -> 2 -> 3 -> 4 -> NONE
+
+    let trunk = List::from(vec![1, 2, 3, 4]).into_head();
+    let branch = &trunk.as_ref().unwrap().next.as_ref().unwrap().next; // [3] -> [4]
+    println!("{:?}", Node::intersection_node(&trunk, branch)); // Some(2)
+    let other = List::from(vec![3, 4]).into_head();
+    println!("{:?}", Node::intersection_node(&trunk, &other)); // None, equal values but different nodes
+
+    // a: [1] -> [2] -> [7] -> [8]
+    // b:        [5] -> [7] -> [8]
+    let shared: Vec<_> = [7, 8].into_iter().map(RcNode::new).collect();
+    shared[0].borrow_mut().next = Some(shared[1].clone());
+    let a_nodes: Vec<_> = [1, 2].into_iter().map(RcNode::new).collect();
+    a_nodes[0].borrow_mut().next = Some(a_nodes[1].clone());
+    a_nodes[1].borrow_mut().next = Some(shared[0].clone());
+    let b_node = RcNode::new(5);
+    b_node.borrow_mut().next = Some(shared[0].clone());
+
+    let a_link = Some(a_nodes[0].clone());
+    let b_link = Some(b_node);
+    println!("{:?}", RcNode::intersection_node(&a_link, &b_link)); // Some(2)
+
+    let separate = Some(RcNode::new(7));
+    println!("{:?}", RcNode::intersection_node(&a_link, &separate)); // None
+
+    let mixed = List::from(vec![1, 2, 3, 4, 5, 6]).into_head();
+    let none_left = Node::retain(mixed, |x| *x > 10);
+    Node::print_list(&none_left); // HEAD -> NONE
+
+    let mixed = List::from(vec![1, 2, 3, 4, 5, 6]).into_head();
+    let all_left = Node::retain(mixed, |x| *x > 0);
+    Node::print_list(&all_left); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> NONE
+
+    let mixed = List::from(vec![1, 2, 3, 4, 5, 6]).into_head();
+    let ends = Node::retain(mixed, |x| !(2..=4).contains(x));
+    Node::print_list(&ends); // HEAD -> 1 -> 5 -> 6 -> NONE
+
+    let repeats = List::from(vec![1, 1, 2, 1, 1]).into_head();
+    Node::print_list(&Node::dedup(repeats)); // HEAD -> 1 -> 2 -> 1 -> NONE
+
+    let alternating = List::from(vec![1, 2, 1, 2]).into_head();
+    Node::print_list(&Node::dedup(alternating)); // HEAD -> 1 -> 2 -> 1 -> 2 -> NONE
+
+    let targets = List::from(vec![7, 1, 7, 7, 2, 7]).into_head();
+    Node::print_list(&Node::remove_all(targets, &7)); // HEAD -> 1 -> 2 -> NONE
+
+    let targets = List::from(vec![1, 2, 3, 3]).into_head();
+    Node::print_list(&Node::remove_all(targets, &3)); // HEAD -> 1 -> 2 -> NONE
+
+    let targets = List::from(vec![7, 7, 7]).into_head();
+    Node::print_list(&Node::remove_all(targets, &7)); // HEAD -> NONE
+
+    let backwards = List::from(vec![5, 4, 3, 2, 1]).into_head();
+    Node::print_list(&Node::sort(backwards)); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> NONE
+
+    let repeated = List::from(vec![3, 1, 2, 3, 1]).into_head();
+    Node::print_list(&Node::sort(repeated)); // HEAD -> 1 -> 1 -> 2 -> 3 -> 3 -> NONE
+
+    let ascending = List::from(vec![1, 4, 2, 5, 3]).into_head();
+    let descending = Node::sort_by(ascending, |a, b| b.cmp(a));
+    Node::print_list(&descending); // HEAD -> 5 -> 4 -> 3 -> 2 -> 1 -> NONE
+
+    // Sorted by length only, so equal-length words stay in their original order
+    let names = List::from(vec!["bob", "al", "eve", "jo", "max"]).into_head();
+    let by_length = Node::sort_by(names, |a, b| a.len().cmp(&b.len()));
+    Node::print_list(&by_length); // HEAD -> al -> jo -> bob -> eve -> max -> NONE
+
+    let positions = List::from(vec![1, 2, 3, 4, 5, 6]).into_head();
+    Node::print_list(&Node::odd_even_list(positions)); // HEAD -> 1 -> 3 -> 5 -> 2 -> 4 -> 6 -> NONE
+
+    let positions = List::from(vec![10, 20, 30, 40, 50]).into_head();
+    Node::print_list(&Node::odd_even_list(positions)); // HEAD -> 10 -> 30 -> 50 -> 20 -> 40 -> NONE
+
+    let positions = List::from(vec![1, 2]).into_head();
+    Node::print_list(&Node::odd_even_list(positions)); // HEAD -> 1 -> 2 -> NONE
+
+    let round_trips = [vec![1, 2, 3], vec![42], vec![], vec![5, 5, 1]];
+    let all_round_trip = round_trips
+        .iter()
+        .all(|v| Node::to_vec(&Node::from_slice(v)) == *v);
+    assert!(all_round_trip);
+    Node::print_list(&Node::from_slice(&[7, 8, 9])); // HEAD -> 7 -> 8 -> 9 -> NONE
+
+    let collected: List<i32> = (1..=5).collect();
+    println!("{}", collected); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> NONE
+
+    let collected: List<&str> = vec!["x", "y"].into_iter().collect();
+    println!("{}", collected); // HEAD -> x -> y -> NONE
+
+    let collected: List<i32> = std::iter::empty().collect();
+    println!("{}", collected); // HEAD -> NONE
+
+    let mut extended = List::from(vec![1, 2, 3]);
+    extended.extend(vec![4, 5, 6]);
+    println!("{}", extended); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> NONE
+
+    let mut extended = List::new();
+    extended.extend(1..=2);
+    println!("{}", extended); // HEAD -> 1 -> 2 -> NONE
+
+    extended.extend(Vec::new());
+    println!("{}", extended); // HEAD -> 1 -> 2 -> NONE
+
+    let mut indexed = List::from(vec![10, 20, 30]);
+    println!("{}", indexed[2]); // 30
+    indexed[2] = 99;
+    indexed[0] += 1;
+    println!("{}", indexed); // HEAD -> 11 -> 20 -> 99 -> NONE
+
+    // Silence the default panic message so only the caught one is printed
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(|| indexed[5]);
+    let _ = std::panic::take_hook();
+    if let Err(payload) = result {
+        println!("{}", payload.downcast_ref::<String>().unwrap()); // index out of bounds: the len is 3 but the index is 5
+    }
+
+    let forwards = Node::from_slice(&[1, 2, 3, 4]);
+    Node::reverse_print(&forwards); // 4 3 2 1
+    assert_eq!(Node::reverse_to_string(&forwards), "4 3 2 1");
+    Node::print_list(&forwards); // HEAD -> 1 -> 2 -> 3 -> 4 -> NONE, still in order
+    let nothing: Option<Box<Node<i32>>> = None;
+    println!("{:?}", Node::reverse_to_string(&nothing)); // ""
+
+    // Both sums have to agree, empty list included
+    let numbers = Node::from_slice(&(1..=1000).collect::<Vec<i64>>());
+    assert_eq!(Node::sum_recursive(&numbers), Node::sum(&numbers));
+    println!("Sum: {}", Node::sum_recursive(&numbers)); // Sum: 500500
+    println!("Sum: {}", Node::sum_recursive(&nothing)); // Sum: 0
+
+    let votes = Node::from_slice(&[3, 1, 3, 3, 2, 1, 3]);
+    println!("{}", Node::count_occurrences(&votes, &3)); // 4, including the run of two
+    println!("{}", Node::count_occurrences(&votes, &2)); // 1
+    println!("{}", Node::count_occurrences(&votes, &7)); // 0
+    println!("{}", Node::count_occurrences(&nothing, &3)); // 0
+
+    let mut marks = Node::from_slice(&[10, 20, 30, 40]);
+    if let Some(node) = Node::get_mut(&mut marks, 2) {
+        node.data += 5;
+    }
+    Node::print_list(&marks); // HEAD -> 10 -> 20 -> 35 -> 40 -> NONE, only index 2 changed
+    assert!(Node::get_mut(&mut marks, 4).is_none());
+    let mut none: Option<Box<Node<i32>>> = None;
+    assert!(Node::get_mut(&mut none, 0).is_none());
+
+    println!("{:?} {:?}", Node::first(&marks), Node::last(&marks)); // Some(10) Some(40)
+    let lone = Node::from_slice(&[7]);
+    println!("{:?} {:?}", Node::first(&lone), Node::last(&lone)); // Some(7) Some(7)
+    println!("{:?} {:?}", Node::first(&nothing), Node::last(&nothing)); // None None
+
+    let mut built = None;
+    for data in [3, 2, 1] {
+        Node::push_front(&mut built, data);
+    }
+    for data in [4, 5, 6] {
+        Node::push_back(&mut built, data);
+    }
+    Node::print_list(&built); // HEAD -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> NONE
+
+    let mut backwards = None;
+    Node::push_back(&mut backwards, 'a'); // push_back onto an empty list sets the head
+    Node::push_front(&mut backwards, 'z');
+    Node::print_list(&backwards); // HEAD -> z -> a -> NONE
+
+    let mut queue = Node::from_slice(&[1, 2, 3]);
+    let mut drained = Vec::new();
+    while let Some(data) = Node::pop_front(&mut queue) {
+        drained.push(data);
+    }
+    println!("{:?}", drained); // [1, 2, 3]
+    println!("{:?}", Node::pop_front(&mut queue)); // None
+
+    let mut stack = Node::from_slice(&[1, 2, 3]);
+    let mut drained = Vec::new();
+    while let Some(data) = Node::pop_back(&mut stack) {
+        drained.push(data);
+    }
+    println!("{:?}", drained); // [3, 2, 1]
+    println!("{:?}", Node::pop_back(&mut stack)); // None
+
+    let short = Node::from_slice(&[1, 2]);
+    let long = Node::from_slice(&[10, 20, 30]);
+    println!("{:?}", Node::zip(&short, &short)); // [(1, 1), (2, 2)]
+    println!("{:?}", Node::zip(&short, &long)); // [(1, 10), (2, 20)]
+    println!("{:?}", Node::zip(&long, &short)); // [(10, 1), (20, 2)]
+    println!("{:?}", Node::zip(&long, &nothing)); // []
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(items: &[i32]) -> Option<Box<Node<i32>>> {
+        Node::from_slice(items)
+    }
+
+    #[test]
+    fn iter_follows_insertion_order() {
+        let mut head = None;
+        for data in [10, 20, 30] {
+            head = Node::insert_at_tail(head, data);
+        }
+
+        let seen: Vec<i32> = Node::iter(&head).copied().collect();
+        assert_eq!(seen, vec![10, 20, 30]);
+
+        let doubled: Vec<i32> = Node::iter(&head).map(|x| x * 2).collect();
+        assert_eq!(doubled, vec![20, 40, 60]);
+    }
+
+    #[test]
+    fn iter_over_empty_list_yields_nothing() {
+        assert_eq!(Node::iter(&list(&[])).count(), 0);
+    }
+
+    #[test]
+    fn into_iter_drains_the_whole_list() {
+        let mut values = Node::into_iter(list(&[30, 20, 10]));
+
+        assert_eq!(values.by_ref().collect::<Vec<_>>(), vec![30, 20, 10]);
+        assert_eq!(values.next(), None);
+    }
+
+    #[test]
+    fn len_counts_every_node() {
+        assert_eq!(Node::len(&list(&[])), 0);
+        assert_eq!(Node::len(&list(&[1])), 1);
+        assert_eq!(Node::len(&list(&[1, 2, 3, 4])), 4);
+    }
+
+    #[test]
+    fn index_operations_are_checked_against_len() {
+        let head = Node::insert_at_index(list(&[1, 3]), 2, 1).unwrap();
+        assert_eq!(Node::to_vec(&head), vec![1, 2, 3]);
+
+        // Inserting at len appends
+        let head = Node::insert_at_index(head, 4, 3).unwrap();
+        assert_eq!(Node::to_vec(&head), vec![1, 2, 3, 4]);
+
+        let head = Node::delete_at_index(head, 0).unwrap();
+        assert_eq!(Node::to_vec(&head), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn out_of_range_index_is_an_error() {
+        assert_eq!(
+            Node::insert_at_index(list(&[1, 2]), 9, 3).err(),
+            Some(ListError::IndexOutOfBounds { index: 3, len: 2 })
+        );
+        assert_eq!(
+            Node::delete_at_index(list(&[1, 2]), 2).err(),
+            Some(ListError::IndexOutOfBounds { index: 2, len: 2 })
+        );
+        assert_eq!(
+            Node::delete_at_index(list(&[]), 0).err(),
+            Some(ListError::IndexOutOfBounds { index: 0, len: 0 })
+        );
+    }
+
+    #[test]
+    fn dropping_a_long_list_does_not_overflow() {
+        let mut long = List::new();
+        for i in 0..1_000_000 {
+            long.push_front(i);
+        }
+
+        assert_eq!(Node::len(&long.head), 1_000_000);
+        drop(long);
+    }
+
+    #[test]
+    fn find_returns_the_first_match() {
+        let head = list(&[10, 20, 30, 20]);
+
+        assert_eq!(Node::find(&head, |x| *x == 10), Some(0));
+        assert_eq!(Node::find(&head, |x| *x == 20), Some(1));
+        assert_eq!(Node::find(&head, |x| *x > 100), None);
+
+        assert!(Node::contains(&head, &30));
+        assert!(!Node::contains(&head, &40));
+    }
+
+    #[test]
+    fn box_list_has_no_cycle() {
+        let head = list(&[1, 2, 3]);
+
+        assert!(!Node::has_cycle(&head));
+        assert_eq!(Node::cycle_start(&head), None);
+        assert!(!Node::has_cycle(&list(&[])));
+    }
+
+    #[test]
+    fn rc_list_cycle_is_found_where_it_starts() {
+        let nodes: Vec<_> = (0..5).map(RcNode::new).collect();
+        for pair in nodes.windows(2) {
+            pair[0].borrow_mut().next = Some(pair[1].clone());
+        }
+        let head = Some(nodes[0].clone());
+
+        assert!(!RcNode::has_cycle(&head));
+        assert_eq!(RcNode::cycle_start(&head), None);
+
+        nodes[4].borrow_mut().next = Some(nodes[2].clone());
+        assert!(RcNode::has_cycle(&head));
+        assert_eq!(RcNode::cycle_start(&head), Some(2));
+
+        // A node pointing at itself
+        nodes[4].borrow_mut().next = Some(nodes[4].clone());
+        assert_eq!(RcNode::cycle_start(&head), Some(4));
+
+        nodes[4].borrow_mut().next = None;
+    }
+
+    #[test]
+    fn find_middle_picks_the_second_of_two_middles() {
+        let odd = list(&[1, 2, 3, 4, 5]);
+        assert_eq!(Node::find_middle(&odd).map(|node| node.data), Some(3));
+
+        let even = list(&[1, 2, 3, 4]);
+        assert_eq!(Node::find_middle(&even).map(|node| node.data), Some(3));
+
+        assert!(Node::find_middle(&list(&[])).is_none());
+    }
+
+    #[test]
+    fn nth_from_end_counts_from_the_tail() {
+        let head = list(&[1, 2, 3, 4]);
+        let nth = |n| Node::nth_from_end(&head, n).map(|node| node.data);
+
+        assert_eq!(nth(0), Some(4));
+        assert_eq!(nth(3), Some(1));
+        assert_eq!(nth(4), None);
+    }
+
+    #[test]
+    fn merge_sorted_interleaves_and_handles_empty_inputs() {
+        let merged = Node::merge_sorted(list(&[1, 3, 5, 7]), list(&[2, 4, 6]));
+        assert_eq!(Node::to_vec(&merged), vec![1, 2, 3, 4, 5, 6, 7]);
+
+        let merged = Node::merge_sorted(list(&[1, 2]), None);
+        assert_eq!(Node::to_vec(&merged), vec![1, 2]);
+        let merged = Node::merge_sorted(None, list(&[1, 2]));
+        assert_eq!(Node::to_vec(&merged), vec![1, 2]);
+        assert!(Node::merge_sorted(list(&[]), list(&[])).is_none());
+
+        // Disjoint ranges, in either order
+        let merged = Node::merge_sorted(list(&[10, 20]), list(&[1, 2]));
+        assert_eq!(Node::to_vec(&merged), vec![1, 2, 10, 20]);
+        let merged = Node::merge_sorted(list(&[1, 2]), list(&[10, 20]));
+        assert_eq!(Node::to_vec(&merged), vec![1, 2, 10, 20]);
+    }
+
+    #[test]
+    fn merge_sort_orders_every_input() {
+        let inputs: [&[i32]; 5] = [
+            &[6, 5, 4, 3, 2, 1],
+            &[2, 1, 3, 2, 1, 3],
+            &[1, 2, 3, 4, 5, 6],
+            &[42],
+            &[],
+        ];
+
+        for input in inputs {
+            let mut expected = input.to_vec();
+            expected.sort();
+            assert_eq!(Node::to_vec(&Node::merge_sort(list(input))), expected);
+        }
+    }
+
+    #[test]
+    fn remove_duplicates_keeps_one_of_each() {
+        let runs = Node::remove_duplicates(list(&[1, 1, 2, 3, 3, 3]));
+        assert_eq!(Node::to_vec(&runs), vec![1, 2, 3]);
+
+        let unique = Node::remove_duplicates(list(&[1, 2, 3]));
+        assert_eq!(Node::to_vec(&unique), vec![1, 2, 3]);
+
+        assert!(Node::remove_duplicates(list(&[])).is_none());
+    }
+
+    #[test]
+    fn from_vec_keeps_the_first_element_at_the_head() {
+        let list = List::from(vec![1, 2, 3]);
+
+        assert_eq!(list.head.as_ref().map(|node| node.data), Some(1));
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert_eq!(List::<i32>::from(vec![]).to_vec(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn display_formats_the_whole_list() {
+        assert_eq!(
+            format!("{}", List::from(vec![30, 20, 10])),
+            "HEAD -> 30 -> 20 -> 10 -> NONE"
+        );
+        assert_eq!(format!("{}", List::<i32>::new()), "HEAD -> NONE");
+    }
+
+    #[test]
+    fn partition_keeps_order_on_both_sides() {
+        let (evens, odds) = Node::partition(list(&[1, 2, 3, 4, 5, 6]), |x| x % 2 == 0);
+
+        assert_eq!(Node::to_vec(&evens), vec![2, 4, 6]);
+        assert_eq!(Node::to_vec(&odds), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn partition_can_leave_one_side_empty() {
+        let (all, none) = Node::partition(list(&[1, 2, 3]), |_| true);
+        assert_eq!(Node::to_vec(&all), vec![1, 2, 3]);
+        assert!(none.is_none());
+
+        let (none, all) = Node::partition(list(&[1, 2, 3]), |_| false);
+        assert!(none.is_none());
+        assert_eq!(Node::to_vec(&all), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn swap_pairs_handles_even_and_odd_lengths() {
+        let even = Node::swap_pairs(list(&[1, 2, 3, 4]));
+        assert_eq!(Node::to_vec(&even), vec![2, 1, 4, 3]);
+
+        let odd = Node::swap_pairs(list(&[1, 2, 3, 4, 5]));
+        assert_eq!(Node::to_vec(&odd), vec![2, 1, 4, 3, 5]);
+
+        assert_eq!(Node::to_vec(&Node::swap_pairs(list(&[1]))), vec![1]);
+        assert!(Node::swap_pairs(list(&[])).is_none());
+    }
+
+    #[test]
+    fn rotate_moves_the_last_k_nodes_to_the_front() {
+        let rotated = Node::rotate(list(&[1, 2, 3, 4, 5]), 2);
+        assert_eq!(Node::to_vec(&rotated), vec![4, 5, 1, 2, 3]);
+
+        // k wraps around the length
+        let rotated = Node::rotate(list(&[1, 2, 3, 4, 5]), 7);
+        assert_eq!(Node::to_vec(&rotated), vec![4, 5, 1, 2, 3]);
+
+        let rotated = Node::rotate(list(&[1, 2, 3]), 3);
+        assert_eq!(Node::to_vec(&rotated), vec![1, 2, 3]);
+        assert!(Node::<i32>::rotate(None, 4).is_none());
+    }
+
+    #[test]
+    fn reverse_between_only_touches_the_range() {
+        let middle = Node::reverse_between(list(&[1, 2, 3, 4, 5]), 1, 3).unwrap();
+        assert_eq!(Node::to_vec(&middle), vec![1, 4, 3, 2, 5]);
+
+        let whole = Node::reverse_between(list(&[1, 2, 3]), 0, 2).unwrap();
+        assert_eq!(Node::to_vec(&whole), vec![3, 2, 1]);
+
+        let single = Node::reverse_between(list(&[1, 2, 3]), 1, 1).unwrap();
+        assert_eq!(Node::to_vec(&single), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn reverse_between_rejects_bad_ranges() {
+        assert_eq!(
+            Node::reverse_between(list(&[1, 2, 3]), 0, 3).err(),
+            Some(ListError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(
+            Node::reverse_between(list(&[1, 2, 3]), 2, 1).err(),
+            Some(ListError::InvalidRange { left: 2, right: 1 })
+        );
+    }
+
+    #[test]
+    fn reverse_k_group_leaves_a_short_last_group_alone() {
+        let groups = Node::reverse_k_group(list(&[1, 2, 3, 4, 5, 6, 7, 8]), 3);
+        assert_eq!(Node::to_vec(&groups), vec![3, 2, 1, 6, 5, 4, 7, 8]);
+
+        let exact = Node::reverse_k_group(list(&[1, 2, 3, 4]), 2);
+        assert_eq!(Node::to_vec(&exact), vec![2, 1, 4, 3]);
+
+        let too_big = Node::reverse_k_group(list(&[1, 2, 3]), 4);
+        assert_eq!(Node::to_vec(&too_big), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn reverse_k_group_with_k_of_zero_or_one_is_a_no_op() {
+        for k in [0, 1] {
+            let same = Node::reverse_k_group(list(&[1, 2, 3]), k);
+            assert_eq!(Node::to_vec(&same), vec![1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn map_builds_a_new_list_of_another_type() {
+        let numbers = list(&[1, 2, 3]);
+        let labels = Node::map(&numbers, |x| format!("#{}", x));
+
+        assert_eq!(Node::to_vec(&labels), vec!["#1", "#2", "#3"]);
+        assert_eq!(Node::to_vec(&numbers), vec![1, 2, 3]);
+        assert!(Node::map(&list(&[]), |x| x * 2).is_none());
+    }
+
+    #[test]
+    fn fold_threads_the_accumulator_front_to_back() {
+        let digits = list(&[1, 2, 3]);
+
+        assert_eq!(Node::fold(&digits, 0, |acc, &x| acc * 10 + x), 123);
+        assert_eq!(Node::sum(&digits), 6);
+        assert_eq!(Node::max(&list(&[3, 9, 2, 9])), Some(&9));
+    }
+
+    #[test]
+    fn fold_over_an_empty_list_returns_init() {
+        assert_eq!(Node::fold(&list(&[]), 7, |acc, &x| acc + x), 7);
+        assert_eq!(Node::sum(&list(&[])), 0);
+        assert_eq!(Node::max(&list(&[])), None);
+    }
+
+    #[test]
+    fn lists_are_equal_only_with_the_same_values_in_order() {
+        let left = List::from(vec![1, 2, 3]);
+
+        assert!(left == List::from(vec![1, 2, 3]));
+        assert!(left != List::from(vec![3, 2, 1]));
+        assert!(left != List::from(vec![1, 2]));
+        assert!(List::from(vec![1, 2]) != left);
+        assert!(List::<i32>::new() == List::new());
+        assert!(List::new() != List::from(vec![0]));
+    }
+
+    #[test]
+    fn clone_is_independent_of_the_original() {
+        let original = List::from(vec![1, 2, 3]);
+        let mut copy = original.clone();
+        Node::traverse_apply(&mut copy.head, |x| *x *= 10);
+
+        assert_eq!(copy.to_vec(), vec![10, 20, 30]);
+        assert_eq!(original.to_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn cloning_a_long_list_does_not_overflow() {
+        let big: List<i32> = List::from((0..1_000_000).collect::<Vec<_>>());
+        assert!(big.clone() == big);
+    }
+
+    #[test]
+    fn split_at_divides_before_the_index() {
+        let (front, back) = Node::split_at(list(&[1, 2, 3, 4, 5]), 2);
+        assert_eq!(Node::to_vec(&front), vec![1, 2]);
+        assert_eq!(Node::to_vec(&back), vec![3, 4, 5]);
+
+        let (front, back) = Node::split_at(list(&[1, 2, 3]), 0);
+        assert!(front.is_none());
+        assert_eq!(Node::to_vec(&back), vec![1, 2, 3]);
+
+        // Past the end, everything stays in the front half
+        let (front, back) = Node::split_at(list(&[1, 2, 3]), 10);
+        assert_eq!(Node::to_vec(&front), vec![1, 2, 3]);
+        assert!(back.is_none());
+    }
+
+    #[test]
+    fn concat_links_the_second_list_after_the_first() {
+        let joined = Node::concat(list(&[1, 2]), list(&[3, 4]));
+        assert_eq!(Node::to_vec(&joined), vec![1, 2, 3, 4]);
+
+        assert_eq!(Node::to_vec(&Node::concat(None, list(&[3]))), vec![3]);
+        assert_eq!(Node::to_vec(&Node::concat(list(&[1]), None)), vec![1]);
+        assert!(Node::<i32>::concat(None, None).is_none());
+    }
+
+    #[test]
+    fn add_two_numbers_carries_between_digits() {
+        // 342 + 465 = 807
+        let sum = Node::add_two_numbers(list(&[2, 4, 3]), list(&[5, 6, 4]));
+        assert_eq!(Node::to_vec(&sum), vec![7, 0, 8]);
+
+        // 99 + 1 = 100, the final carry adds a digit
+        let sum = Node::add_two_numbers(list(&[9, 9]), list(&[1]));
+        assert_eq!(Node::to_vec(&sum), vec![0, 0, 1]);
+
+        let sum = Node::add_two_numbers(list(&[5]), None);
+        assert_eq!(Node::to_vec(&sum), vec![5]);
+        assert!(Node::add_two_numbers(None, None).is_none());
+    }
+
+    #[test]
+    fn is_palindrome_checks_both_parities() {
+        for (items, expected) in [
+            (&[1, 2, 3, 2, 1][..], true),
+            (&[1, 2, 2, 1], true),
+            (&[1, 2, 3, 4], false),
+            (&[1, 2, 3, 1], false),
+            (&[7], true),
+            (&[], true),
+        ] {
+            let mut head = list(items);
+
+            assert_eq!(Node::is_palindrome(&mut head), expected, "{:?}", items);
+            // The second half is reversed back afterwards
+            assert_eq!(Node::to_vec(&head), items);
+        }
+    }
+
+    #[test]
+    fn intersection_node_needs_shared_nodes_not_equal_values() {
+        let trunk = list(&[1, 2, 3, 4]);
+        let branch = &trunk.as_ref().unwrap().next.as_ref().unwrap().next;
+
+        assert_eq!(Node::intersection_node(&trunk, branch), Some(2));
+        assert_eq!(Node::intersection_node(&trunk, &trunk), Some(0));
+        assert_eq!(Node::intersection_node(&trunk, &list(&[3, 4])), None);
+        assert_eq!(Node::intersection_node(&trunk, &None), None);
+    }
+
+    #[test]
+    fn rc_lists_that_merge_intersect_where_they_meet() {
+        // a: [1] -> [2] -> [7] -> [8]
+        // b:        [5] -> [7] -> [8]
+        let shared = RcNode::new(7);
+        shared.borrow_mut().next = Some(RcNode::new(8));
+        let a = RcNode::new(1);
+        let a_second = RcNode::new(2);
+        a_second.borrow_mut().next = Some(shared.clone());
+        a.borrow_mut().next = Some(a_second);
+        let b = RcNode::new(5);
+        b.borrow_mut().next = Some(shared);
+
+        let a = Some(a);
+        let separate = Some(RcNode::new(7));
+        assert_eq!(RcNode::intersection_node(&a, &Some(b)), Some(2));
+        assert_eq!(RcNode::intersection_node(&a, &separate), None);
+    }
+
+    #[test]
+    fn retain_keeps_only_accepted_values_in_order() {
+        let kept = Node::retain(list(&[1, 2, 3, 4, 5, 6]), |x| x % 2 == 0);
+        assert_eq!(Node::to_vec(&kept), vec![2, 4, 6]);
+
+        // Removing the head and runs of neighbours
+        let kept = Node::retain(list(&[1, 1, 2, 1, 1]), |&x| x != 1);
+        assert_eq!(Node::to_vec(&kept), vec![2]);
+
+        let kept = Node::retain(list(&[1, 2]), |_| true);
+        assert_eq!(Node::to_vec(&kept), vec![1, 2]);
+        assert!(Node::retain(list(&[1, 2]), |_| false).is_none());
+    }
+
+    #[test]
+    fn dedup_only_removes_adjacent_repeats() {
+        let deduped = Node::dedup(list(&[1, 1, 2, 1, 1]));
+        assert_eq!(Node::to_vec(&deduped), vec![1, 2, 1]);
+
+        let alternating = Node::dedup(list(&[1, 2, 1, 2]));
+        assert_eq!(Node::to_vec(&alternating), vec![1, 2, 1, 2]);
+
+        let same = Node::dedup(list(&[7, 7, 7]));
+        assert_eq!(Node::to_vec(&same), vec![7]);
+    }
+
+    #[test]
+    fn remove_all_deletes_every_copy() {
+        let removed = Node::remove_all(list(&[3, 1, 3, 3, 2, 3]), &3);
+        assert_eq!(Node::to_vec(&removed), vec![1, 2]);
+
+        let untouched = Node::remove_all(list(&[1, 2]), &3);
+        assert_eq!(Node::to_vec(&untouched), vec![1, 2]);
+
+        assert!(Node::remove_all(list(&[3, 3]), &3).is_none());
+    }
+
+    #[test]
+    fn sort_and_sort_by_agree_with_std() {
+        let items = [5, 3, 9, 1, 3, 7, 2];
+        let mut ascending = items.to_vec();
+        ascending.sort();
+        let mut descending = ascending.clone();
+        descending.reverse();
+
+        assert_eq!(Node::to_vec(&Node::sort(list(&items))), ascending);
+        let sorted = Node::sort_by(list(&items), |a, b| b.cmp(a));
+        assert_eq!(Node::to_vec(&sorted), descending);
+        assert!(Node::sort(list(&[])).is_none());
+    }
+
+    #[test]
+    fn sort_by_is_stable() {
+        let words = Node::from_slice(&["pear", "fig", "kiwi", "apple", "plum", "date"]);
+        let by_length = Node::sort_by(words, |a, b| a.len().cmp(&b.len()));
+
+        // Words of the same length keep their original order
+        assert_eq!(
+            Node::to_vec(&by_length),
+            vec!["fig", "pear", "kiwi", "plum", "date", "apple"]
+        );
+    }
+
+    #[test]
+    fn odd_even_list_groups_by_position() {
+        let grouped = Node::odd_even_list(list(&[1, 2, 3, 4, 5]));
+        assert_eq!(Node::to_vec(&grouped), vec![1, 3, 5, 2, 4]);
+
+        // Positions, not values, decide the group
+        let grouped = Node::odd_even_list(list(&[2, 1, 4, 3]));
+        assert_eq!(Node::to_vec(&grouped), vec![2, 4, 1, 3]);
+
+        assert_eq!(Node::to_vec(&Node::odd_even_list(list(&[1]))), vec![1]);
+        assert!(Node::odd_even_list(list(&[])).is_none());
+    }
+
+    #[test]
+    fn from_slice_and_to_vec_round_trip() {
+        for items in [vec![1, 2, 3], vec![42], vec![], vec![5, 5, 1]] {
+            assert_eq!(Node::to_vec(&list(&items)), items);
+        }
+        assert!(list(&[]).is_none());
+    }
+
+    #[test]
+    fn from_slice_keeps_the_first_element_at_the_head() {
+        let head = list(&[7, 8, 9]);
+        assert_eq!(head.as_ref().map(|node| node.data), Some(7));
+        assert_eq!(Node::len(&head), 3);
+
+        let words = Node::from_slice(&["a".to_string(), "b".to_string()]);
+        assert_eq!(Node::to_vec(&words), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn collect_keeps_iteration_order() {
+        let collected: List<i32> = (1..=5).collect();
+        assert_eq!(collected.to_vec(), vec![1, 2, 3, 4, 5]);
+
+        let words: List<&str> = vec!["x", "y"].into_iter().collect();
+        assert_eq!(words.to_vec(), vec!["x", "y"]);
+
+        let empty: List<i32> = std::iter::empty().collect();
+        assert!(empty.head.is_none());
+    }
+
+    #[test]
+    fn collecting_a_long_iterator_does_not_overflow() {
+        let collected: List<u32> = (0..100_000).collect();
+        assert_eq!(Node::len(&collected.head), 100_000);
+    }
+
+    #[test]
+    fn extend_appends_after_the_last_node() {
+        let mut extended = List::from(vec![1, 2, 3]);
+        extended.extend(vec![4, 5, 6]);
+        assert_eq!(extended.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+
+        extended.extend(7..=8);
+        assert_eq!(extended.to_vec(), vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn extend_handles_empty_lists_on_either_side() {
+        let mut extended = List::new();
+        extended.extend(1..=2);
+        assert_eq!(extended.to_vec(), vec![1, 2]);
+
+        extended.extend(Vec::new());
+        assert_eq!(extended.to_vec(), vec![1, 2]);
+
+        let mut empty: List<i32> = List::new();
+        empty.extend(Vec::new());
+        assert!(empty.head.is_none());
+    }
+
+    #[test]
+    fn index_reads_and_writes_in_place() {
+        let mut indexed = List::from(vec![10, 20, 30]);
+        assert_eq!(indexed[0], 10);
+        assert_eq!(indexed[2], 30);
+
+        indexed[2] = 99;
+        indexed[0] += 1;
+        assert_eq!(indexed.to_vec(), vec![11, 20, 99]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 5")]
+    fn index_past_the_end_panics_like_vec() {
+        let indexed = List::from(vec![10, 20, 30]);
+        let _ = indexed[5];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+    fn index_mut_on_an_empty_list_panics() {
+        let mut empty: List<i32> = List::new();
+        empty[0] = 1;
+    }
+
+    #[test]
+    fn reverse_to_string_reads_back_to_front() {
+        assert_eq!(Node::reverse_to_string(&list(&[1, 2, 3, 4])), "4 3 2 1");
+        assert_eq!(Node::reverse_to_string(&list(&[7])), "7");
+        assert_eq!(Node::reverse_to_string(&list(&[])), "");
+
+        let words = Node::from_slice(&["a", "b"]);
+        assert_eq!(Node::reverse_to_string(&words), "b a");
+    }
+
+    #[test]
+    fn reverse_to_string_leaves_the_list_alone() {
+        let head = list(&[1, 2, 3]);
+        Node::reverse_to_string(&head);
+        assert_eq!(Node::to_vec(&head), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn reverse_to_string_handles_a_long_list() {
+        // Held in a List so dropping it doesn't recurse 100000 deep either
+        let long: List<i32> = (0..100_000).collect();
+        let text = Node::reverse_to_string(&long.head);
+
+        assert!(text.starts_with("99999 99998"));
+        assert!(text.ends_with("1 0"));
+    }
+
+    #[test]
+    fn sum_recursive_agrees_with_sum() {
+        for items in [vec![], vec![5], vec![1, 2, 3, 4], vec![-3, 3, -7]] {
+            let head = list(&items);
+            let expected: i32 = items.iter().sum();
+
+            assert_eq!(Node::sum_recursive(&head), expected);
+            assert_eq!(Node::sum(&head), expected);
+        }
+    }
+
+    #[test]
+    fn sum_recursive_works_for_other_number_types() {
+        let numbers = Node::from_slice(&(1..=1000).collect::<Vec<i64>>());
+        assert_eq!(Node::sum_recursive(&numbers), 500_500);
+
+        let halves = Node::from_slice(&[0.5, 0.25, 0.25]);
+        assert_eq!(Node::sum_recursive(&halves), 1.0);
+    }
+
+    #[test]
+    fn count_occurrences_counts_every_match() {
+        let votes = list(&[3, 1, 3, 3, 2, 1, 3]);
+
+        assert_eq!(Node::count_occurrences(&votes, &3), 4);
+        assert_eq!(Node::count_occurrences(&votes, &1), 2);
+        assert_eq!(Node::count_occurrences(&votes, &2), 1);
+        assert_eq!(Node::count_occurrences(&votes, &7), 0);
+        assert_eq!(Node::count_occurrences(&list(&[]), &3), 0);
+    }
+
+    #[test]
+    fn count_occurrences_compares_by_value() {
+        let names = Node::from_slice(&["ann".to_string(), "bo".to_string()]);
+        let ann = "ann".to_string();
+
+        assert_eq!(Node::count_occurrences(&names, &ann), 1);
+    }
+
+    #[test]
+    fn get_mut_edits_only_the_chosen_node() {
+        let mut marks = list(&[10, 20, 30, 40]);
+
+        if let Some(node) = Node::get_mut(&mut marks, 2) {
+            node.data += 5;
+        }
+        Node::get_mut(&mut marks, 0).unwrap().data = 1;
+        Node::get_mut(&mut marks, 3).unwrap().data *= 2;
+
+        assert_eq!(Node::to_vec(&marks), vec![1, 20, 35, 80]);
+    }
+
+    #[test]
+    fn get_mut_past_the_end_is_none() {
+        let mut marks = list(&[10, 20]);
+        assert!(Node::get_mut(&mut marks, 2).is_none());
+        assert!(Node::get_mut(&mut marks, 100).is_none());
+        assert!(Node::get_mut(&mut list(&[]), 0).is_none());
+        assert_eq!(Node::to_vec(&marks), vec![10, 20]);
+    }
+
+    #[test]
+    fn first_and_last_read_the_ends() {
+        let marks = list(&[10, 20, 30, 40]);
+        assert_eq!(Node::first(&marks), Some(&10));
+        assert_eq!(Node::last(&marks), Some(&40));
+
+        let lone = list(&[7]);
+        assert_eq!(Node::first(&lone), Some(&7));
+        assert_eq!(Node::last(&lone), Some(&7));
+
+        assert_eq!(Node::first(&list(&[])), None);
+        assert_eq!(Node::last(&list(&[])), None);
+    }
+
+    #[test]
+    fn push_front_and_push_back_grow_either_end() {
+        let mut built = None;
+        for data in [3, 2, 1] {
+            Node::push_front(&mut built, data);
+        }
+        for data in [4, 5, 6] {
+            Node::push_back(&mut built, data);
+        }
+
+        assert_eq!(Node::to_vec(&built), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn push_back_onto_an_empty_list_sets_the_head() {
+        let mut head = None;
+        Node::push_back(&mut head, 'a');
+        assert_eq!(Node::first(&head), Some(&'a'));
+
+        Node::push_front(&mut head, 'z');
+        assert_eq!(Node::to_vec(&head), vec!['z', 'a']);
+    }
+
+    #[test]
+    fn pop_front_drains_in_order() {
+        let mut queue = list(&[1, 2, 3]);
+        let drained: Vec<i32> = std::iter::from_fn(|| Node::pop_front(&mut queue)).collect();
+
+        assert_eq!(drained, vec![1, 2, 3]);
+        assert!(queue.is_none());
+        assert_eq!(Node::pop_front(&mut queue), None);
+    }
+
+    #[test]
+    fn pop_back_drains_in_reverse() {
+        let mut stack = list(&[1, 2, 3]);
+        let drained: Vec<i32> = std::iter::from_fn(|| Node::pop_back(&mut stack)).collect();
+
+        assert_eq!(drained, vec![3, 2, 1]);
+        assert!(stack.is_none());
+        assert_eq!(Node::pop_back(&mut stack), None);
+    }
+
+    #[test]
+    fn pops_from_both_ends_meet_in_the_middle() {
+        let mut head = list(&[1, 2, 3, 4, 5]);
+
+        assert_eq!(Node::pop_front(&mut head), Some(1));
+        assert_eq!(Node::pop_back(&mut head), Some(5));
+        assert_eq!(Node::to_vec(&head), vec![2, 3, 4]);
+
+        assert_eq!(Node::pop_back(&mut list(&[9])), Some(9));
+    }
+
+    #[test]
+    fn zip_stops_at_the_shorter_list() {
+        let short = list(&[1, 2]);
+        let long = list(&[10, 20, 30]);
+
+        assert_eq!(Node::zip(&short, &short), vec![(1, 1), (2, 2)]);
+        assert_eq!(Node::zip(&short, &long), vec![(1, 10), (2, 20)]);
+        assert_eq!(Node::zip(&long, &short), vec![(10, 1), (20, 2)]);
+    }
+
+    #[test]
+    fn zip_with_an_empty_list_is_empty() {
+        let long = list(&[10, 20, 30]);
+
+        assert!(Node::zip(&long, &list(&[])).is_empty());
+        assert!(Node::zip(&list(&[]), &long).is_empty());
+        assert!(Node::zip(&list(&[]), &list(&[])).is_empty());
+    }
+
+    #[test]
+    fn middle_index_splits_merge_sort_into_two_non_empty_halves() {
+        for (items, index) in [(&[1, 2][..], 1), (&[1, 2, 3], 1), (&[1, 2, 3, 4], 2)] {
+            let head = list(items);
+            let (mid, node) = Node::middle(&head);
+
+            assert_eq!(mid, index);
+            assert_eq!(node.map(|node| node.data), Some(items[index]));
+        }
+        assert_eq!(Node::middle(&list(&[])).0, 0);
+    }
+
+    #[test]
+    fn sort_handles_every_small_length() {
+        for n in 0..20 {
+            let items: Vec<i32> = (0..n).map(|i| (i * 7) % 5).collect();
+            let mut expected = items.clone();
+            expected.sort();
+
+            assert_eq!(Node::to_vec(&Node::sort(list(&items))), expected);
+        }
+    }
+
+    #[test]
+    fn extending_the_same_list_twice_appends_both_times() {
+        let mut list = List::from(vec![1, 2]);
+        list.extend(vec![3, 4]);
+        list.extend(5..=6);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+
+        // One element at a time, each after the one before
+        let mut one_by_one = List::new();
+        for i in 0..10_000 {
+            one_by_one.extend([i]);
+        }
+        assert_eq!(one_by_one.to_vec(), (0..10_000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn tail_follows_push_front_from_head_and_clone() {
+        let mut list = List::new();
+        list.push_front(2);
+        list.push_front(1);
+        list.extend([3]);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+
+        let mut rebuilt = List::from_head(list.clone().into_head());
+        rebuilt.extend([4]);
+        assert_eq!(rebuilt.to_vec(), vec![1, 2, 3, 4]);
+
+        let mut empty = List::from_head(None);
+        empty.extend([7]);
+        assert_eq!(empty.to_vec(), vec![7]);
+
+        let mut copy = list.clone();
+        copy.extend([9]);
+        assert_eq!(copy.to_vec(), vec![1, 2, 3, 9]);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    }
 }
@@ -0,0 +1,234 @@
+use std::ptr;
+
+#[allow(dead_code)]
+struct Node {
+    data: i32,
+    next: Option<Box<Node>>,
+}
+
+// `head` owns every node. `tail` points at the last one so push_back doesn't have to
+// walk the list, and is null exactly when the list is empty.
+#[allow(dead_code)]
+struct List {
+    head: Option<Box<Node>>,
+    tail: *mut Node,
+    len: usize,
+}
+
+#[allow(dead_code)]
+impl List {
+    fn new() -> Self {
+        List {
+            head: None,
+            tail: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    fn push_front(&mut self, data: i32) {
+        let old_head = self.head.take();
+        let new_node = self.head.insert(Box::new(Node {
+            data,
+            next: old_head,
+        }));
+
+        // The new node is also the last one if the list was empty
+        if self.tail.is_null() {
+            self.tail = &mut **new_node;
+        }
+
+        self.len += 1;
+    }
+
+    // O(1): no walk, the new node goes straight after `tail`
+    fn push_back(&mut self, data: i32) {
+        let new_node = Box::new(Node { data, next: None });
+
+        let slot = if self.tail.is_null() {
+            &mut self.head
+        } else {
+            // SAFETY: `tail` is only non-null while it points at the last node owned
+            // by `head`, and `&mut self` means nothing else is borrowing that node.
+            unsafe { &mut (*self.tail).next }
+        };
+
+        // Take the pointer from the node after it has moved into the list
+        self.tail = &mut **slot.insert(new_node);
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) -> Option<i32> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+
+            // The last node just left, so `tail` would dangle
+            if self.head.is_none() {
+                self.tail = ptr::null_mut();
+            }
+
+            self.len -= 1;
+            node.data
+        })
+    }
+
+    // Still O(n): a singly linked node can't see the one before it, so finding the
+    // new tail means walking from the head
+    fn pop_back(&mut self) -> Option<i32> {
+        let mut current = self.head.as_mut()?;
+
+        if current.next.is_none() {
+            self.tail = ptr::null_mut();
+            self.len -= 1;
+            return self.head.take().map(|node| node.data);
+        }
+
+        while current.next.as_ref().unwrap().next.is_some() {
+            current = current.next.as_mut().unwrap();
+        }
+
+        let last = current.next.take().unwrap();
+
+        // `last` is freed when this returns, so `tail` moves back to the node before it
+        self.tail = &mut **current;
+        self.len -= 1;
+
+        Some(last.data)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    // Walks to the last node and checks `tail` points at it. Comparing pointers never
+    // dereferences them, so this is safe even if `tail` were dangling.
+    fn tail_is_valid(&self) -> bool {
+        let mut current = &self.head;
+        let mut last: *const Node = ptr::null();
+
+        while let Some(node) = current {
+            last = &**node;
+            current = &node.next;
+        }
+
+        ptr::eq(last, self.tail)
+    }
+
+    fn print_list(&self) {
+        let mut current = &self.head;
+
+        print!("HEAD -> ");
+
+        while let Some(node) = current {
+            print!("{} -> ", node.data);
+            current = &node.next;
+        }
+
+        println!("NONE");
+    }
+}
+
+// Same as the singly list: drop one node at a time instead of recursively
+impl Drop for List {
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+
+        while let Some(mut node) = current {
+            current = node.next.take();
+        }
+    }
+}
+
+fn main() {
+    let mut list = List::new();
+
+    list.push_back(10); // HEAD -> [10] -> NONE
+    list.push_back(20); // HEAD -> [10] -> [20] -> NONE
+    list.push_front(5); // HEAD -> [5] -> [10] -> [20] -> NONE
+    list.push_back(30); // HEAD -> [5] -> [10] -> [20] -> [30] -> NONE
+    list.print_list(); // HEAD -> 5 -> 10 -> 20 -> 30 -> NONE
+
+    println!("{:?}", list.pop_back()); // Some(30)
+    list.push_back(40); // The tail moved back to 20, so 40 goes after it
+    list.print_list(); // HEAD -> 5 -> 10 -> 20 -> 40 -> NONE
+    assert!(list.tail_is_valid());
+
+    // Drain from the front until empty, then refill: the tail must be reset in between
+    while list.pop_front().is_some() {}
+    println!("Length: {}", list.len()); // Length: 0
+    assert!(list.tail_is_valid());
+
+    list.push_back(1);
+    list.push_back(2);
+    list.print_list(); // HEAD -> 1 -> 2 -> NONE
+
+    // Interleave appends and removals from the front
+    let mut all_valid = true;
+    for i in 3..1000 {
+        list.push_back(i);
+        if i % 3 == 0 {
+            list.pop_front();
+        }
+        all_valid &= list.tail_is_valid();
+    }
+    assert!(all_valid);
+    println!("Length: {}", list.len()); // Length: 666
+
+    println!("{:?}", list.pop_back()); // Some(999)
+    println!("{:?}", list.pop_front()); // Some(334)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(list: &mut List) -> Vec<i32> {
+        std::iter::from_fn(|| list.pop_front()).collect()
+    }
+
+    #[test]
+    fn push_back_appends_after_the_tail() {
+        let mut list = List::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+
+        assert!(list.tail_is_valid());
+        assert_eq!(list.len(), 3);
+        assert_eq!(drain(&mut list), vec![1, 2, 3]);
+        assert!(list.tail.is_null());
+    }
+
+    #[test]
+    fn pop_back_moves_the_tail_back() {
+        let mut list = List::new();
+        for data in [1, 2, 3] {
+            list.push_back(data);
+        }
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert!(list.tail_is_valid());
+        list.push_back(4);
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.tail.is_null());
+    }
+
+    #[test]
+    fn tail_stays_valid_through_mixed_operations() {
+        let mut list = List::new();
+        for i in 0..500 {
+            match i % 4 {
+                0 => list.push_front(i),
+                1 | 2 => list.push_back(i),
+                _ => {
+                    list.pop_back();
+                }
+            }
+            assert!(list.tail_is_valid(), "after step {}", i);
+        }
+
+        assert_eq!(list.len(), 250);
+    }
+}
@@ -0,0 +1,157 @@
+// Walks the outer ring clockwise, then the next ring in, and so on. The four bounds
+// close in by one after each side is walked. Works for any rectangular matrix,
+// including empty ones.
+fn spiral_order(m: &[Vec<i32>]) -> Vec<i32> {
+    let mut result = Vec::new();
+    if m.is_empty() || m[0].is_empty() {
+        return result;
+    }
+
+    // Half-open bounds: rows top..bottom and columns left..right are still unvisited
+    let (mut top, mut bottom) = (0, m.len());
+    let (mut left, mut right) = (0, m[0].len());
+
+    while top < bottom && left < right {
+        result.extend_from_slice(&m[top][left..right]);
+        top += 1;
+
+        for row in m.iter().take(bottom).skip(top) {
+            result.push(row[right - 1]);
+        }
+        right -= 1;
+
+        // With a single row or column left, the two sides above already covered it
+        if top < bottom {
+            result.extend(m[bottom - 1][left..right].iter().rev());
+            bottom -= 1;
+        }
+
+        if left < right {
+            for row in m.iter().take(bottom).skip(top).rev() {
+                result.push(row[left]);
+            }
+            left += 1;
+        }
+    }
+
+    result
+}
+
+// Rotates a square matrix 90 degrees clockwise in place. Transposing flips it over
+// the main diagonal, then reversing each row mirrors it left to right.
+fn rotate_90(m: &mut [Vec<i32>]) {
+    let n = m.len();
+    assert!(
+        m.iter().all(|row| row.len() == n),
+        "rotate_90 needs a square matrix"
+    );
+
+    // Swap each element above the diagonal with its mirror below it. Row i and the
+    // rows after it are split apart so both can be borrowed mutably at once.
+    for i in 0..n {
+        let (upper, lower) = m.split_at_mut(i + 1);
+        let row = &mut upper[i];
+
+        for (offset, other) in lower.iter_mut().enumerate() {
+            std::mem::swap(&mut row[i + 1 + offset], &mut other[i]);
+        }
+    }
+
+    for row in m.iter_mut() {
+        row.reverse();
+    }
+}
+
+fn main() {
+    let square = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+    println!("{:?}", spiral_order(&square)); // [1, 2, 3, 6, 9, 8, 7, 4, 5]
+
+    let wide = vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]];
+    println!("{:?}", spiral_order(&wide)); // [1, 2, 3, 4, 8, 12, 11, 10, 9, 5, 6, 7]
+
+    let tall = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+    println!("{:?}", spiral_order(&tall)); // [1, 2, 4, 6, 5, 3]
+
+    let row = vec![vec![1, 2, 3]];
+    println!("{:?}", spiral_order(&row)); // [1, 2, 3]
+
+    let column = vec![vec![1], vec![2], vec![3]];
+    println!("{:?}", spiral_order(&column)); // [1, 2, 3]
+
+    let empty: Vec<Vec<i32>> = vec![];
+    println!("{:?}", spiral_order(&empty)); // []
+
+    let mut rotated = square.clone();
+    rotate_90(&mut rotated);
+    println!("{:?}", rotated); // [[7, 4, 1], [8, 5, 2], [9, 6, 3]]
+
+    // Four quarter turns get back to the start
+    for _ in 0..3 {
+        rotate_90(&mut rotated);
+    }
+    assert_eq!(rotated, square);
+
+    let mut single = vec![vec![42]];
+    rotate_90(&mut single);
+    println!("{:?}", single); // [[42]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // rows x cols, numbered 1, 2, 3, ... row by row
+    fn numbered(rows: usize, cols: usize) -> Vec<Vec<i32>> {
+        (0..rows)
+            .map(|r| (0..cols).map(|c| (r * cols + c + 1) as i32).collect())
+            .collect()
+    }
+
+    #[test]
+    fn spiral_order_handles_every_shape() {
+        assert_eq!(spiral_order(&numbered(3, 3)), [1, 2, 3, 6, 9, 8, 7, 4, 5]);
+        assert_eq!(
+            spiral_order(&numbered(3, 4)),
+            [1, 2, 3, 4, 8, 12, 11, 10, 9, 5, 6, 7]
+        );
+        assert_eq!(spiral_order(&numbered(3, 2)), [1, 2, 4, 6, 5, 3]);
+        assert_eq!(spiral_order(&numbered(1, 3)), [1, 2, 3]);
+        assert_eq!(spiral_order(&numbered(3, 1)), [1, 2, 3]);
+        assert!(spiral_order(&[]).is_empty());
+        assert!(spiral_order(&[vec![]]).is_empty());
+    }
+
+    #[test]
+    fn spiral_order_visits_every_element_once() {
+        for rows in 1..6 {
+            for cols in 1..6 {
+                let mut visited = spiral_order(&numbered(rows, cols));
+                visited.sort();
+                assert_eq!(visited, (1..=(rows * cols) as i32).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_90_turns_clockwise() {
+        let mut m = numbered(3, 3);
+        rotate_90(&mut m);
+        assert_eq!(m, vec![vec![7, 4, 1], vec![8, 5, 2], vec![9, 6, 3]]);
+
+        let mut m = numbered(4, 4);
+        for _ in 0..4 {
+            rotate_90(&mut m);
+        }
+        assert_eq!(m, numbered(4, 4));
+
+        let mut empty: Vec<Vec<i32>> = vec![];
+        rotate_90(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "rotate_90 needs a square matrix")]
+    fn rotate_90_rejects_non_square_matrices() {
+        rotate_90(&mut numbered(2, 3));
+    }
+}
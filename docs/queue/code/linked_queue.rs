@@ -0,0 +1,137 @@
+use std::ptr;
+
+#[allow(dead_code)]
+struct Node {
+    data: i32,
+    next: Option<Box<Node>>,
+}
+
+// Dequeue from the head, enqueue at the tail. `head` owns every node, while
+// `tail` is a raw pointer to the last one so enqueue doesn't have to walk the list.
+#[allow(dead_code)]
+struct Queue {
+    head: Option<Box<Node>>,
+    tail: *mut Node,
+    len: usize,
+}
+
+#[allow(dead_code)]
+impl Queue {
+    fn new() -> Self {
+        Queue {
+            head: None,
+            tail: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    fn enqueue(&mut self, data: i32) {
+        let new_node = Box::new(Node { data, next: None });
+
+        if self.tail.is_null() {
+            self.head = Some(new_node);
+            self.tail = self.head.as_deref_mut().unwrap();
+        } else {
+            // SAFETY: `tail` is only non-null while it points at the last node owned
+            // by `head`, so it is valid and nothing else is borrowing that node.
+            unsafe {
+                (*self.tail).next = Some(new_node);
+                self.tail = (*self.tail).next.as_deref_mut().unwrap();
+            }
+        }
+
+        self.len += 1;
+    }
+
+    fn dequeue(&mut self) -> Option<i32> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+
+            // The last node just left, so `tail` would dangle
+            if self.head.is_none() {
+                self.tail = ptr::null_mut();
+            }
+
+            self.len -= 1;
+            node.data
+        })
+    }
+
+    fn front(&self) -> Option<&i32> {
+        self.head.as_ref().map(|node| &node.data)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+fn main() {
+    let mut queue = Queue::new();
+
+    queue.enqueue(10); // FRONT -> [10] <- BACK
+    queue.enqueue(20); // FRONT -> [10] -> [20] <- BACK
+    queue.enqueue(30); // FRONT -> [10] -> [20] -> [30] <- BACK
+
+    println!("Front: {:?}", queue.front()); // Front: Some(10)
+    println!("Length: {}", queue.len()); // Length: 3
+
+    println!("{:?}", queue.dequeue()); // Some(10)
+    println!("{:?}", queue.dequeue()); // Some(20)
+    println!("{:?}", queue.dequeue()); // Some(30)
+    println!("{:?}", queue.dequeue()); // None
+    println!("Empty: {}", queue.is_empty()); // Empty: true
+
+    // Refill after emptying, the tail must have been reset
+    queue.enqueue(40); // FRONT -> [40] <- BACK
+    queue.enqueue(50); // FRONT -> [40] -> [50] <- BACK
+
+    println!("Front: {:?}", queue.front()); // Front: Some(40)
+    println!("{:?}", queue.dequeue()); // Some(40)
+    println!("{:?}", queue.dequeue()); // Some(50)
+    println!("Length: {}", queue.len()); // Length: 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dequeue_returns_the_first_enqueue_first() {
+        let mut queue = Queue::new();
+        for data in [1, 2, 3] {
+            queue.enqueue(data);
+        }
+
+        assert_eq!(queue.front(), Some(&1));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+
+        // Enqueue after a dequeue still goes to the back
+        queue.enqueue(4);
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn queue_can_be_refilled_after_emptying() {
+        let mut queue = Queue::new();
+        queue.enqueue(1);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert!(queue.is_empty());
+        assert!(queue.tail.is_null());
+
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert_eq!(queue.front(), Some(&2));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.len(), 0);
+    }
+}
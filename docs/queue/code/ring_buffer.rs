@@ -0,0 +1,137 @@
+#[derive(Debug)]
+struct FullError;
+
+// `head` is the index of the front element and `tail` is the index where the next
+// element goes. Both wrap around with `% capacity`. When head == tail the buffer is
+// either empty or full, so `len` is what tells the two apart.
+#[allow(dead_code)]
+struct RingBuffer {
+    buf: Vec<Option<i32>>,
+    head: usize,
+    tail: usize,
+    len: usize,
+}
+
+#[allow(dead_code)]
+impl RingBuffer {
+    fn new(capacity: usize) -> Self {
+        RingBuffer {
+            buf: vec![None; capacity],
+            head: 0,
+            tail: 0,
+            len: 0,
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn enqueue(&mut self, data: i32) -> Result<(), FullError> {
+        if self.is_full() {
+            return Err(FullError);
+        }
+
+        self.buf[self.tail] = Some(data);
+        self.tail = (self.tail + 1) % self.capacity();
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn dequeue(&mut self) -> Option<i32> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let data = self.buf[self.head].take();
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+
+        data
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+fn main() {
+    let mut ring = RingBuffer::new(3);
+
+    ring.enqueue(10).unwrap(); // [10, _, _] head = 0, tail = 1
+    ring.enqueue(20).unwrap(); // [10, 20, _] head = 0, tail = 2
+    ring.enqueue(30).unwrap(); // [10, 20, 30] head = 0, tail = 0
+
+    println!("Full: {}", ring.is_full()); // Full: true
+    println!("{:?}", ring.enqueue(40)); // Err(FullError)
+
+    println!("{:?}", ring.dequeue()); // Some(10)
+    ring.enqueue(40).unwrap(); // [40, 20, 30] head = 1, tail = 1
+
+    // Keep cycling so head and tail wrap around several times
+    for i in 0..7 {
+        let front = ring.dequeue().unwrap();
+        ring.enqueue(50 + i).unwrap();
+        print!("{} ", front);
+    }
+    println!(); // 20 30 40 50 51 52 53
+
+    println!("{:?}", ring.dequeue()); // Some(54)
+    println!("{:?}", ring.dequeue()); // Some(55)
+    println!("{:?}", ring.dequeue()); // Some(56)
+    println!("{:?}", ring.dequeue()); // None
+    println!("Empty: {}", ring.is_empty()); // Empty: true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enqueue_fails_only_when_full() {
+        let mut ring = RingBuffer::new(2);
+
+        assert!(ring.enqueue(1).is_ok());
+        assert!(ring.enqueue(2).is_ok());
+        assert!(ring.is_full());
+        assert!(ring.enqueue(3).is_err());
+
+        // A failed enqueue leaves the contents alone
+        assert_eq!(ring.dequeue(), Some(1));
+        assert!(ring.enqueue(3).is_ok());
+        assert_eq!(ring.dequeue(), Some(2));
+        assert_eq!(ring.dequeue(), Some(3));
+        assert_eq!(ring.dequeue(), None);
+    }
+
+    #[test]
+    fn order_survives_many_wraparounds() {
+        let mut ring = RingBuffer::new(3);
+        ring.enqueue(0).unwrap();
+        ring.enqueue(1).unwrap();
+
+        for i in 2..100 {
+            ring.enqueue(i).unwrap();
+            assert_eq!(ring.dequeue(), Some(i - 2));
+        }
+
+        assert_eq!(ring.dequeue(), Some(98));
+        assert_eq!(ring.dequeue(), Some(99));
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn zero_capacity_is_always_full_and_empty() {
+        let mut ring = RingBuffer::new(0);
+
+        assert!(ring.is_full());
+        assert!(ring.is_empty());
+        assert!(ring.enqueue(1).is_err());
+        assert_eq!(ring.dequeue(), None);
+    }
+}
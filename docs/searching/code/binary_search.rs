@@ -0,0 +1,107 @@
+// All three searches work on half-open ranges [lo, hi) over a sorted slice.
+
+// Index of the first element >= target, or arr.len() if there is none.
+// This is also where target would be inserted to keep the slice sorted.
+fn lower_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+    let mut lo = 0;
+    let mut hi = arr.len();
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+
+        if arr[mid] < *target {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    lo
+}
+
+// Index of the first element > target, or arr.len() if there is none
+fn upper_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+    let mut lo = 0;
+    let mut hi = arr.len();
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+
+        if arr[mid] <= *target {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    lo
+}
+
+// Returns the index of the first occurrence of target
+fn binary_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    let i = lower_bound(arr, target);
+
+    if i < arr.len() && arr[i] == *target {
+        Some(i)
+    } else {
+        None
+    }
+}
+
+fn main() {
+    //         0  1  2  3  4  5  6  7
+    let arr = [1, 3, 3, 3, 5, 8, 8, 13];
+
+    println!("{:?}", binary_search(&arr, &5)); // Some(4)
+    println!("{:?}", binary_search(&arr, &4)); // None
+
+    // The run of 3s covers [lower_bound, upper_bound)
+    let first = lower_bound(&arr, &3);
+    let last = upper_bound(&arr, &3) - 1;
+    println!("First 3: {}, last 3: {}", first, last); // First 3: 1, last 3: 3
+
+    println!("{:?}", binary_search(&arr, &8)); // Some(5)
+    println!("Last 8: {}", upper_bound(&arr, &8) - 1); // Last 8: 6
+
+    // Smaller than everything: insert at the front
+    println!("{:?}", binary_search(&arr, &0)); // None
+    println!("{} {}", lower_bound(&arr, &0), upper_bound(&arr, &0)); // 0 0
+
+    // Larger than everything: insert at the end
+    println!("{:?}", binary_search(&arr, &20)); // None
+    println!("{} {}", lower_bound(&arr, &20), upper_bound(&arr, &20)); // 8 8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARR: [i32; 8] = [1, 3, 3, 3, 5, 8, 8, 13];
+
+    #[test]
+    fn bounds_bracket_every_run_of_equal_values() {
+        for target in [1, 3, 5, 8, 13] {
+            let first = ARR.iter().position(|&x| x == target).unwrap();
+            let last = ARR.iter().rposition(|&x| x == target).unwrap();
+
+            assert_eq!(lower_bound(&ARR, &target), first);
+            assert_eq!(upper_bound(&ARR, &target), last + 1);
+        }
+    }
+
+    #[test]
+    fn missing_values_give_their_insertion_point() {
+        assert_eq!(binary_search(&ARR, &4), None);
+        assert_eq!((lower_bound(&ARR, &4), upper_bound(&ARR, &4)), (4, 4));
+        assert_eq!((lower_bound(&ARR, &0), upper_bound(&ARR, &0)), (0, 0));
+        assert_eq!((lower_bound(&ARR, &20), upper_bound(&ARR, &20)), (8, 8));
+        assert_eq!(binary_search(&[] as &[i32], &1), None);
+    }
+
+    #[test]
+    fn binary_search_finds_the_first_occurrence() {
+        assert_eq!(binary_search(&ARR, &3), Some(1));
+        assert_eq!(binary_search(&ARR, &8), Some(5));
+        assert_eq!(binary_search(&ARR, &13), Some(7));
+    }
+}
@@ -0,0 +1,126 @@
+// The k-th smallest element (0-indexed), in average O(n). Like quicksort, but after
+// each partition only the side that contains index k is kept, so the rest of the
+// slice is never sorted. Reorders arr along the way.
+fn quickselect<T: Ord + Clone>(arr: &mut [T], k: usize) -> T {
+    assert!(
+        k < arr.len(),
+        "quickselect: k is {} but the length is {}",
+        k,
+        arr.len()
+    );
+
+    // Half-open range [lo, hi) that still contains index k
+    let mut lo = 0;
+    let mut hi = arr.len();
+
+    loop {
+        let p = lo + partition(&mut arr[lo..hi]);
+
+        if k == p {
+            return arr[k].clone();
+        } else if k < p {
+            hi = p;
+        } else {
+            lo = p + 1;
+        }
+    }
+}
+
+// Lomuto partition, same as in sorts.rs: returns the pivot's final index, with
+// everything smaller than the pivot to its left and everything else to its right
+fn partition<T: Ord>(arr: &mut [T]) -> usize {
+    let pivot = arr.len() - 1; // PIVOT: the last element
+    let mut store = 0;
+
+    for i in 0..pivot {
+        if arr[i] < arr[pivot] {
+            arr.swap(i, store);
+            store += 1;
+        }
+    }
+
+    arr.swap(store, pivot);
+    store
+}
+
+fn main() {
+    let data = vec![38, 27, 43, 3, 9, 82, 10, 3];
+    let mut sorted = data.clone();
+    sorted.sort();
+    println!("{:?}", sorted); // [3, 3, 9, 10, 27, 38, 43, 82]
+
+    println!("{}", quickselect(&mut data.clone(), 0)); // 3
+    println!("{}", quickselect(&mut data.clone(), 3)); // 10
+    println!("{}", quickselect(&mut data.clone(), 7)); // 82
+
+    // Every k must agree with the sorted array
+    let all_match = (0..data.len()).all(|k| quickselect(&mut data.clone(), k) == sorted[k]);
+    assert!(all_match);
+
+    // Median of an odd-length slice
+    let mut words = vec!["pear", "apple", "fig", "banana", "kiwi"];
+    println!("{}", quickselect(&mut words, 2)); // fig
+
+    // Silence the default panic message so only the caught one is printed
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(|| quickselect(&mut [1, 2, 3], 3));
+    let _ = std::panic::take_hook();
+    if let Err(payload) = result {
+        println!("{}", payload.downcast_ref::<String>().unwrap()); // quickselect: k is 3 but the length is 3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quickselect_agrees_with_sorting() {
+        let inputs = [
+            vec![38, 27, 43, 3, 9, 82, 10, 3],
+            vec![5, 4, 3, 2, 1],
+            vec![1, 2, 3, 4, 5],
+            vec![7, 7, 7, 7],
+            vec![42],
+            (0..200).map(|i| i * 7919 % 200).collect(),
+        ];
+
+        for data in inputs {
+            let mut sorted = data.clone();
+            sorted.sort();
+            for (k, expected) in sorted.iter().enumerate() {
+                assert_eq!(quickselect(&mut data.clone(), k), *expected);
+            }
+        }
+    }
+
+    #[test]
+    fn quickselect_keeps_the_same_elements() {
+        let mut data = vec![38, 27, 43, 3, 9, 82, 10, 3];
+        assert_eq!(quickselect(&mut data, 4), 27);
+
+        // Reordered, but nothing added or lost, and k is now in its sorted place
+        assert_eq!(data[4], 27);
+        data.sort();
+        assert_eq!(data, vec![3, 3, 9, 10, 27, 38, 43, 82]);
+    }
+
+    #[test]
+    fn quickselect_works_on_strings() {
+        let mut words = vec!["pear", "apple", "fig", "banana", "kiwi"];
+        assert_eq!(quickselect(&mut words, 2), "fig");
+        assert_eq!(quickselect(&mut words, 0), "apple");
+    }
+
+    #[test]
+    #[should_panic(expected = "quickselect: k is 3 but the length is 3")]
+    fn quickselect_rejects_k_past_the_end() {
+        quickselect(&mut [1, 2, 3], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "quickselect: k is 0 but the length is 0")]
+    fn quickselect_rejects_an_empty_slice() {
+        quickselect::<i32>(&mut [], 0);
+    }
+}
@@ -0,0 +1,143 @@
+// A complete binary tree stored in a flat Vec: the leaves are tree[n..2n] (one per
+// element) and tree[i] holds the sum of its children tree[2i] and tree[2i + 1].
+// tree[1] is the root and covers the whole array; tree[0] is unused.
+#[allow(dead_code)]
+struct SegmentTree {
+    tree: Vec<i64>,
+    n: usize,
+}
+
+#[allow(dead_code)]
+impl SegmentTree {
+    fn build(data: &[i64]) -> Self {
+        let n = data.len();
+        let mut tree = vec![0; 2 * n];
+
+        tree[n..].copy_from_slice(data);
+        for i in (1..n).rev() {
+            tree[i] = tree[2 * i] + tree[2 * i + 1];
+        }
+
+        SegmentTree { tree, n }
+    }
+
+    // Set the leaf, then fix every sum on the way up to the root: O(log n)
+    fn point_update(&mut self, i: usize, value: i64) {
+        let mut i = i + self.n;
+        self.tree[i] = value;
+
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = self.tree[2 * i] + self.tree[2 * i + 1];
+        }
+    }
+
+    // Sum of the half-open range [l, r). Both ends climb towards the root. Whenever
+    // an end sits on a node whose parent would reach outside the range, that node's
+    // sum is added on its own and the end steps inwards. O(log n).
+    fn range_sum(&self, l: usize, r: usize) -> i64 {
+        let mut l = l + self.n;
+        let mut r = r + self.n;
+        let mut sum = 0;
+
+        while l < r {
+            // l is a right child, so its parent also covers the element before l
+            if l % 2 == 1 {
+                sum += self.tree[l];
+                l += 1;
+            }
+            // r is exclusive: if it is a right child, its left sibling is inside the range
+            if r % 2 == 1 {
+                r -= 1;
+                sum += self.tree[r];
+            }
+
+            l /= 2;
+            r /= 2;
+        }
+
+        sum
+    }
+}
+
+// prefix[i] is the sum of the first i elements, so [l, r) sums to prefix[r] - prefix[l]
+fn prefix_sums(data: &[i64]) -> Vec<i64> {
+    let mut prefix = vec![0; data.len() + 1];
+
+    for (i, x) in data.iter().enumerate() {
+        prefix[i + 1] = prefix[i] + x;
+    }
+
+    prefix
+}
+
+// Checks every range [l, r) against the brute-force answer
+fn matches_brute_force(tree: &SegmentTree, data: &[i64]) -> bool {
+    let prefix = prefix_sums(data);
+
+    (0..=data.len())
+        .all(|l| (l..=data.len()).all(|r| tree.range_sum(l, r) == prefix[r] - prefix[l]))
+}
+
+fn main() {
+    //          0  1  2  3  4   5  6  7
+    let data = [5, 3, 7, 9, 6, -4, 1, 2];
+    let mut tree = SegmentTree::build(&data);
+
+    println!("{}", tree.range_sum(0, 8)); // 29
+    println!("{}", tree.range_sum(2, 5)); // 22
+    println!("{}", tree.range_sum(3, 3)); // 0
+    assert!(matches_brute_force(&tree, &data));
+
+    tree.point_update(2, 10); // 7 becomes 10
+    println!("{}", tree.range_sum(2, 5)); // 25
+
+    let mut updated = data;
+    updated[2] = 10;
+    assert!(matches_brute_force(&tree, &updated));
+
+    // An odd length means the leaves don't line up with a perfect tree, but the sums still work
+    let mut odd: Vec<i64> = (1..=11).collect();
+    let mut odd_tree = SegmentTree::build(&odd);
+    for i in (0..odd.len()).step_by(3) {
+        odd[i] *= -1;
+        odd_tree.point_update(i, odd[i]);
+    }
+    assert!(matches_brute_force(&odd_tree, &odd));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_sum_covers_half_open_ranges() {
+        let tree = SegmentTree::build(&[5, 3, 7, 9, 6, -4, 1, 2]);
+
+        assert_eq!(tree.range_sum(0, 8), 29);
+        assert_eq!(tree.range_sum(2, 5), 22);
+        assert_eq!(tree.range_sum(5, 6), -4);
+        assert_eq!(tree.range_sum(3, 3), 0);
+    }
+
+    #[test]
+    fn every_range_matches_after_updates() {
+        for len in 1..=13 {
+            let mut data: Vec<i64> = (0..len).map(|i| i * i - 10).collect();
+            let mut tree = SegmentTree::build(&data);
+            assert!(matches_brute_force(&tree, &data), "length {}", len);
+
+            for i in (0..data.len()).step_by(2) {
+                data[i] = 100 - data[i];
+                tree.point_update(i, data[i]);
+            }
+            assert!(matches_brute_force(&tree, &data), "length {}", len);
+        }
+    }
+
+    #[test]
+    fn empty_tree_sums_to_zero() {
+        let tree = SegmentTree::build(&[]);
+        assert_eq!(tree.range_sum(0, 0), 0);
+    }
+}
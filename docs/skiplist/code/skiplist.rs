@@ -0,0 +1,361 @@
+const MAX_LEVEL: usize = 32;
+
+// xorshift64: a tiny deterministic generator, so the same inserts always build the
+// same levels. Good enough for coin flips, not for anything that needs real randomness.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+// forward[i] is the next node on level i. A node with forward.len() == 3 appears on
+// levels 0, 1 and 2. Nodes live in a Vec and link to each other by index.
+#[allow(dead_code)]
+struct SkipNode {
+    key: i32,
+    forward: Vec<Option<usize>>,
+}
+
+// Level 0 is an ordinary sorted linked list. Each level above it skips over roughly
+// half the nodes of the one below, so a search can drop down level by level and
+// only walk a few nodes on each: expected O(log n).
+#[allow(dead_code)]
+struct SkipList {
+    nodes: Vec<SkipNode>, // nodes[0] is the head, with no key of its own
+    free: Vec<usize>,     // Slots left behind by removed nodes
+    level: usize,         // Number of levels currently in use
+    len: usize,
+    rng: Rng,
+}
+
+#[allow(dead_code)]
+impl SkipList {
+    fn new(seed: u64) -> Self {
+        let head = SkipNode {
+            key: i32::MIN,
+            forward: vec![None; MAX_LEVEL],
+        };
+
+        SkipList {
+            nodes: vec![head],
+            free: Vec::new(),
+            level: 1,
+            len: 0,
+            rng: Rng(seed.max(1)), // xorshift gets stuck at 0
+        }
+    }
+
+    // Flip coins until tails: a node reaches level i with probability 1/2^i
+    fn random_level(&mut self) -> usize {
+        let mut level = 1;
+
+        while level < MAX_LEVEL && self.rng.next() & 1 == 1 {
+            level += 1;
+        }
+
+        level
+    }
+
+    // For every level, the last node whose key is < key. These are the nodes whose
+    // links change when key is inserted or removed.
+    fn predecessors(&self, key: i32) -> [usize; MAX_LEVEL] {
+        let mut update = [0; MAX_LEVEL];
+        let mut x = 0;
+
+        for i in (0..self.level).rev() {
+            while let Some(next) = self.nodes[x].forward[i] {
+                if self.nodes[next].key >= key {
+                    break;
+                }
+                x = next;
+            }
+
+            update[i] = x;
+        }
+
+        update
+    }
+
+    fn contains(&self, key: i32) -> bool {
+        let before = self.predecessors(key)[0];
+
+        match self.nodes[before].forward[0] {
+            Some(next) => self.nodes[next].key == key,
+            None => false,
+        }
+    }
+
+    // Returns false if key was already present
+    fn insert(&mut self, key: i32) -> bool {
+        if self.contains(key) {
+            return false;
+        }
+
+        let mut update = self.predecessors(key);
+        let level = self.random_level();
+
+        // New levels start out empty, so the head is the predecessor there
+        if level > self.level {
+            for slot in update.iter_mut().take(level).skip(self.level) {
+                *slot = 0;
+            }
+            self.level = level;
+        }
+
+        let node = SkipNode {
+            key,
+            forward: vec![None; level],
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+
+        // Splice the node in on every level it reaches, just like a linked list insert
+        for (i, &before) in update.iter().enumerate().take(level) {
+            self.nodes[index].forward[i] = self.nodes[before].forward[i];
+            self.nodes[before].forward[i] = Some(index);
+        }
+
+        self.len += 1;
+        true
+    }
+
+    // Returns false if key wasn't present
+    fn remove(&mut self, key: i32) -> bool {
+        let update = self.predecessors(key);
+
+        let target = match self.nodes[update[0]].forward[0] {
+            Some(next) if self.nodes[next].key == key => next,
+            _ => return false,
+        };
+
+        for (i, &before) in update.iter().enumerate().take(self.level) {
+            if self.nodes[before].forward[i] == Some(target) {
+                self.nodes[before].forward[i] = self.nodes[target].forward[i];
+            }
+        }
+
+        // Drop levels that no longer have any nodes on them
+        while self.level > 1 && self.nodes[0].forward[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+
+        self.free.push(target);
+        self.len -= 1;
+        true
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn to_vec(&self) -> Vec<i32> {
+        let mut keys = Vec::new();
+        let mut current = self.nodes[0].forward[0];
+
+        while let Some(index) = current {
+            keys.push(self.nodes[index].key);
+            current = self.nodes[index].forward[0];
+        }
+
+        keys
+    }
+
+    fn print_levels(&self) {
+        for i in (0..self.level).rev() {
+            print!("L{}: HEAD -> ", i);
+
+            let mut current = self.nodes[0].forward[i];
+            while let Some(index) = current {
+                print!("{} -> ", self.nodes[index].key);
+                current = self.nodes[index].forward[i];
+            }
+
+            println!("NONE");
+        }
+    }
+}
+
+fn main() {
+    let mut list = SkipList::new(42);
+
+    for key in [30, 10, 50, 20, 40, 60] {
+        list.insert(key);
+    }
+
+    println!("{:?}", list.to_vec()); // [10, 20, 30, 40, 50, 60]
+    list.print_levels();
+    // L2: HEAD -> 40 -> NONE
+    // L1: HEAD -> 10 -> 40 -> NONE
+    // L0: HEAD -> 10 -> 20 -> 30 -> 40 -> 50 -> 60 -> NONE
+
+    println!("{} {}", list.contains(40), list.contains(45)); // true false
+    println!("Inserted again: {}", list.insert(40)); // Inserted again: false
+
+    println!("Removed: {}", list.remove(40)); // Removed: true
+    println!("Removed: {}", list.remove(40)); // Removed: false
+
+    // 40 was the only node on L2, so that level is gone
+    list.print_levels();
+    // L1: HEAD -> 10 -> NONE
+    // L0: HEAD -> 10 -> 20 -> 30 -> 50 -> 60 -> NONE
+
+    // Every third number from 0 to 2997, inserted in a scrambled order
+    let n = 1000;
+    let mut big = SkipList::new(7);
+    for i in 0..n {
+        big.insert((i * 7 % n) * 3);
+    }
+
+    let all_present = (0..n).all(|i| big.contains(i * 3));
+    let none_extra = (0..n).all(|i| !big.contains(i * 3 + 1));
+    assert!(all_present && none_extra);
+    println!("Length: {}", big.len()); // Length: 1000
+
+    // About log2(1000) ~ 10 levels are expected; twice that would be very unlikely
+    println!("Levels: {}", big.level); // Levels: 11
+    assert!(big.level <= 2 * 10);
+
+    for i in 0..n / 2 {
+        big.remove(i * 6);
+    }
+    println!("Length: {}", big.len()); // Length: 500
+    println!("{:?}", &big.to_vec()[..4]); // [3, 9, 15, 21]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeSet;
+
+    fn level_keys(list: &SkipList, i: usize) -> Vec<i32> {
+        let mut keys = Vec::new();
+        let mut current = list.nodes[0].forward[i];
+
+        while let Some(index) = current {
+            keys.push(list.nodes[index].key);
+            current = list.nodes[index].forward[i];
+        }
+
+        keys
+    }
+
+    // Every level is sorted and only holds keys from the level below it
+    fn assert_levels_hold(list: &SkipList) {
+        for i in 0..list.level {
+            let keys = level_keys(list, i);
+            assert!(keys.windows(2).all(|w| w[0] < w[1]), "level {} unsorted", i);
+
+            if i > 0 {
+                let below: BTreeSet<i32> = level_keys(list, i - 1).into_iter().collect();
+                assert!(keys.iter().all(|key| below.contains(key)));
+            }
+        }
+        assert!(list.level == 1 || list.nodes[0].forward[list.level - 1].is_some());
+    }
+
+    #[test]
+    fn insert_and_remove_report_whether_anything_changed() {
+        let mut list = SkipList::new(42);
+
+        assert!(list.insert(5));
+        assert!(!list.insert(5));
+        assert!(list.contains(5));
+        assert!(list.remove(5));
+        assert!(!list.remove(5));
+        assert!(!list.contains(5));
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.level, 1);
+    }
+
+    #[test]
+    fn matches_a_btree_set_through_random_operations() {
+        let mut list = SkipList::new(3);
+        let mut expected = BTreeSet::new();
+        let mut rng = Rng(99);
+
+        for _ in 0..5000 {
+            let key = (rng.next() % 200) as i32;
+
+            if rng.next().is_multiple_of(3) {
+                assert_eq!(list.remove(key), expected.remove(&key));
+            } else {
+                assert_eq!(list.insert(key), expected.insert(key));
+            }
+        }
+
+        assert_levels_hold(&list);
+        assert_eq!(list.len(), expected.len());
+        assert_eq!(list.to_vec(), expected.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn removed_slots_are_reused() {
+        let mut list = SkipList::new(1);
+        for key in 0..10 {
+            list.insert(key);
+        }
+        for key in 0..10 {
+            list.remove(key);
+        }
+        for key in 10..20 {
+            list.insert(key);
+        }
+
+        assert_eq!(list.nodes.len(), 11);
+        assert_eq!(list.to_vec(), (10..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn level_count_stays_within_twice_log2_n() {
+        // Some node passes 2 * log2(n) with probability about 1 / n, so a small n would
+        // make this flaky across seeds
+        for n in [1000, 10_000] {
+            let bound = 2 * (n as f64).log2().ceil() as usize;
+
+            for seed in 1..=20 {
+                let mut list = SkipList::new(seed);
+                for i in 0..n {
+                    list.insert(i * 7919 % n);
+                }
+
+                assert!(
+                    list.level <= bound,
+                    "n = {}, seed = {}: {} levels",
+                    n,
+                    seed,
+                    list.level
+                );
+                assert_levels_hold(&list);
+            }
+        }
+    }
+
+    #[test]
+    fn each_level_holds_about_half_the_level_below() {
+        let mut list = SkipList::new(7);
+        for i in 0..10_000 {
+            list.insert(i);
+        }
+
+        // Coin flips, so only loosely: between a quarter and three quarters
+        for i in 1..6 {
+            let below = level_keys(&list, i - 1).len();
+            let above = level_keys(&list, i).len();
+            assert!(below / 4 <= above && above <= below * 3 / 4, "level {}", i);
+        }
+    }
+}
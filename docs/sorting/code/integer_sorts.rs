@@ -0,0 +1,187 @@
+// Neither sort compares elements with each other. They use the values themselves as
+// indices into a table of counts, which is how they get under the O(n log n) limit
+// that applies to comparison sorts.
+
+// Count how many times each value 0..=max appears, then write the values back out
+// in order. O(n + max) time and O(max) extra space, so only worth it for small max.
+fn counting_sort(arr: &mut [u32], max: u32) {
+    assert!(
+        arr.iter().all(|&x| x <= max),
+        "counting_sort: every value must be at most max ({})",
+        max
+    );
+
+    let mut counts = vec![0; max as usize + 1];
+
+    for &x in arr.iter() {
+        counts[x as usize] += 1;
+    }
+
+    let mut i = 0;
+    for (value, &count) in counts.iter().enumerate() {
+        for _ in 0..count {
+            arr[i] = value as u32;
+            i += 1;
+        }
+    }
+}
+
+// LSD radix sort: a stable counting sort on each byte, least significant byte first.
+// Every pass keeps the order of the earlier ones for equal bytes, so after the last
+// (most significant) pass the whole value is sorted. Four passes for a u32: O(4n).
+fn radix_sort(arr: &mut [u32]) {
+    let mut buffer = vec![0; arr.len()];
+
+    for shift in [0, 8, 16, 24] {
+        let byte = |x: u32| ((x >> shift) & 0xff) as usize;
+
+        let mut counts = [0; 256];
+        for &x in arr.iter() {
+            counts[byte(x)] += 1;
+        }
+
+        // Turn counts into starting positions: starts[b] is where the first value
+        // with byte b goes
+        let mut starts = [0; 256];
+        for b in 1..256 {
+            starts[b] = starts[b - 1] + counts[b - 1];
+        }
+
+        // Walking front to back and filling each bucket in order is what makes it stable
+        for &x in arr.iter() {
+            buffer[starts[byte(x)]] = x;
+            starts[byte(x)] += 1;
+        }
+
+        arr.copy_from_slice(&buffer);
+    }
+}
+
+fn main() {
+    let mut grades = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+    counting_sort(&mut grades, 9);
+    println!("{:?}", grades); // [1, 1, 2, 3, 3, 4, 5, 5, 5, 6, 9]
+
+    let mut same = vec![7, 7, 7];
+    counting_sort(&mut same, 7);
+    println!("{:?}", same); // [7, 7, 7]
+
+    // Values spanning all four bytes, so every radix pass matters
+    let mut wide = vec![
+        4_000_000_000,
+        256,
+        255,
+        65_536,
+        1,
+        16_777_216,
+        0,
+        65_535,
+        256,
+    ];
+    radix_sort(&mut wide);
+    println!("{:?}", wide);
+    // [0, 1, 255, 256, 256, 65535, 65536, 16777216, 4000000000]
+
+    let mut single = vec![42];
+    radix_sort(&mut single);
+    println!("{:?}", single); // [42]
+
+    // Both must agree with the standard library on a larger input
+    let input: Vec<u32> = (0..1000u32)
+        .map(|i| i.wrapping_mul(2_654_435_761) % 1000)
+        .collect();
+    let mut expected = input.clone();
+    expected.sort();
+
+    let mut counted = input.clone();
+    counting_sort(&mut counted, 999);
+    let mut radixed = input.clone();
+    radix_sort(&mut radixed);
+    assert_eq!(counted, expected);
+    assert_eq!(radixed, expected);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(arr: &[u32]) -> Vec<u32> {
+        let mut expected = arr.to_vec();
+        expected.sort();
+        expected
+    }
+
+    #[test]
+    fn counting_sort_orders_small_values() {
+        let mut grades = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+        counting_sort(&mut grades, 9);
+        assert_eq!(grades, vec![1, 1, 2, 3, 3, 4, 5, 5, 5, 6, 9]);
+
+        let mut same = vec![7, 7, 7];
+        counting_sort(&mut same, 7);
+        assert_eq!(same, vec![7, 7, 7]);
+
+        let mut zeros = vec![0, 0];
+        counting_sort(&mut zeros, 0);
+        assert_eq!(zeros, vec![0, 0]);
+
+        let mut empty: Vec<u32> = vec![];
+        counting_sort(&mut empty, 5);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn counting_sort_allows_a_max_above_every_value() {
+        let mut arr = vec![2, 0, 1];
+        counting_sort(&mut arr, 100);
+        assert_eq!(arr, vec![0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "counting_sort: every value must be at most max (9)")]
+    fn counting_sort_rejects_a_value_above_max() {
+        counting_sort(&mut [3, 10, 1], 9);
+    }
+
+    #[test]
+    fn radix_sort_orders_values_across_all_bytes() {
+        let mut wide = vec![
+            4_000_000_000,
+            256,
+            255,
+            65_536,
+            1,
+            16_777_216,
+            0,
+            65_535,
+            256,
+        ];
+        let expected = sorted(&wide);
+        radix_sort(&mut wide);
+        assert_eq!(wide, expected);
+
+        let mut extremes = vec![u32::MAX, 0, u32::MAX - 1, 1];
+        radix_sort(&mut extremes);
+        assert_eq!(extremes, vec![0, 1, u32::MAX - 1, u32::MAX]);
+
+        let mut empty: Vec<u32> = vec![];
+        radix_sort(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn both_sorts_agree_with_std() {
+        let input: Vec<u32> = (0..1000u32)
+            .map(|i| i.wrapping_mul(2_654_435_761) % 1000)
+            .collect();
+        let expected = sorted(&input);
+
+        let mut counted = input.clone();
+        counting_sort(&mut counted, 999);
+        assert_eq!(counted, expected);
+
+        let mut radixed = input.clone();
+        radix_sort(&mut radixed);
+        assert_eq!(radixed, expected);
+    }
+}
@@ -0,0 +1,316 @@
+// Repeatedly swap adjacent out-of-order pairs. After pass i, the largest i
+// elements have bubbled to the end. Stops early if a pass makes no swaps.
+fn bubble_sort<T: Ord>(arr: &mut [T]) {
+    let n = arr.len();
+
+    for i in 0..n {
+        let mut swapped = false;
+
+        for j in 0..n - 1 - i {
+            if arr[j] > arr[j + 1] {
+                arr.swap(j, j + 1);
+                swapped = true;
+            }
+        }
+
+        if !swapped {
+            break;
+        }
+    }
+}
+
+// Grow a sorted prefix by sliding each new element left until it fits
+fn insertion_sort<T: Ord>(arr: &mut [T]) {
+    for i in 1..arr.len() {
+        let mut j = i;
+
+        while j > 0 && arr[j - 1] > arr[j] {
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct SortStats {
+    comparisons: usize,
+    swaps: usize,
+}
+
+// insertion_sort, counting its work. Sorted input never slides anything, so it costs
+// n - 1 comparisons and no swaps. Reversed input slides every element all the way
+// left: n(n - 1) / 2 of each.
+fn insertion_sort_instrumented(arr: &mut [i32]) -> SortStats {
+    let mut stats = SortStats {
+        comparisons: 0,
+        swaps: 0,
+    };
+
+    for i in 1..arr.len() {
+        let mut j = i;
+
+        while j > 0 {
+            stats.comparisons += 1;
+            if arr[j - 1] <= arr[j] {
+                break;
+            }
+
+            arr.swap(j - 1, j);
+            stats.swaps += 1;
+            j -= 1;
+        }
+    }
+
+    stats
+}
+
+// Find the smallest remaining element and swap it into the next position
+fn selection_sort<T: Ord>(arr: &mut [T]) {
+    let n = arr.len();
+
+    for i in 0..n {
+        let mut min = i;
+
+        for j in i + 1..n {
+            if arr[j] < arr[min] {
+                min = j;
+            }
+        }
+
+        arr.swap(i, min);
+    }
+}
+
+fn quick_sort<T: Ord>(arr: &mut [T]) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let p = partition(arr);
+
+    quick_sort(&mut arr[..p]);
+    quick_sort(&mut arr[p + 1..]);
+}
+
+// Lomuto partition: returns the pivot's final index, with everything smaller
+// than the pivot to its left and everything else to its right
+fn partition<T: Ord>(arr: &mut [T]) -> usize {
+    let pivot = arr.len() - 1; // PIVOT: the last element
+    let mut store = 0;
+
+    for i in 0..pivot {
+        if arr[i] < arr[pivot] {
+            arr.swap(i, store);
+            store += 1;
+        }
+    }
+
+    arr.swap(store, pivot);
+    store
+}
+
+// Same idea as quick_sort, but the sub-ranges waiting to be sorted live on an explicit
+// stack instead of the call stack. After each partition the larger side is pushed and
+// the loop keeps going on the smaller side, so the stack never holds more than
+// O(log n) ranges, even when partitions are badly unbalanced.
+fn quick_sort_iterative<T: Ord>(arr: &mut [T]) {
+    let mut stack: Vec<(usize, usize)> = vec![(0, arr.len())]; // Half-open ranges [lo, hi)
+
+    while let Some((mut lo, mut hi)) = stack.pop() {
+        while hi - lo > 1 {
+            median_of_three(&mut arr[lo..hi]);
+            let p = lo + partition(&mut arr[lo..hi]);
+
+            if p - lo < hi - (p + 1) {
+                stack.push((p + 1, hi));
+                hi = p;
+            } else {
+                stack.push((lo, p));
+                lo = p + 1;
+            }
+        }
+    }
+}
+
+// Moves the median of the first, middle and last elements to the end, where
+// `partition` picks its pivot. Without this, an already sorted slice would make
+// every partition as lopsided as possible and the sort would take O(n^2) time.
+fn median_of_three<T: Ord>(arr: &mut [T]) {
+    let mid = arr.len() / 2;
+    let last = arr.len() - 1;
+
+    if arr[mid] < arr[0] {
+        arr.swap(0, mid);
+    }
+    if arr[last] < arr[0] {
+        arr.swap(0, last);
+    }
+    // arr[0] is now the smallest of the three, so the median is the smaller of the other two
+    if arr[mid] < arr[last] {
+        arr.swap(mid, last);
+    }
+}
+
+type SortFn = fn(&mut [i32]);
+
+fn main() {
+    let sorts: [(&str, SortFn); 5] = [
+        ("bubble", bubble_sort),
+        ("insertion", insertion_sort),
+        ("selection", selection_sort),
+        ("quick", quick_sort),
+        ("iterative quick", quick_sort_iterative),
+    ];
+
+    let inputs = [
+        vec![38, 27, 43, 3, 9, 82, 10, 3], // Random, with a duplicate
+        vec![1, 2, 3, 4, 5, 6],            // Already sorted
+        vec![6, 5, 4, 3, 2, 1],            // Reversed
+        vec![42],                          // Single element
+        vec![],                            // Empty
+    ];
+
+    // Every sort must agree with the standard library on every input
+    for (name, sort) in sorts {
+        let all_match = inputs.iter().all(|input| {
+            let mut actual = input.clone();
+            let mut expected = input.clone();
+
+            sort(&mut actual);
+            expected.sort();
+
+            actual == expected
+        });
+
+        assert!(all_match, "{} sort disagrees with std", name);
+    }
+
+    let mut words = vec!["pear", "apple", "fig", "banana"];
+    quick_sort(&mut words);
+    println!("{:?}", words); // ["apple", "banana", "fig", "pear"]
+
+    // The worst case for a last-element pivot. The recursive quick_sort would go
+    // 100000 calls deep here, the iterative one finishes without growing the call stack.
+    let mut sorted: Vec<i32> = (0..100_000).collect();
+    quick_sort_iterative(&mut sorted);
+    assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+
+    let mut presorted = [1, 2, 3, 4, 5, 6];
+    println!("{:?}", insertion_sort_instrumented(&mut presorted));
+    // SortStats { comparisons: 5, swaps: 0 }
+
+    let mut reversed = [6, 5, 4, 3, 2, 1];
+    println!("{:?}", insertion_sort_instrumented(&mut reversed));
+    // SortStats { comparisons: 15, swaps: 15 }
+    println!("{:?}", reversed); // [1, 2, 3, 4, 5, 6]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SORTS: [(&str, SortFn); 5] = [
+        ("bubble", bubble_sort),
+        ("insertion", insertion_sort),
+        ("selection", selection_sort),
+        ("quick", quick_sort),
+        ("iterative quick", quick_sort_iterative),
+    ];
+
+    fn inputs() -> Vec<Vec<i32>> {
+        vec![
+            vec![38, 27, 43, 3, 9, 82, 10, 3],
+            vec![1, 2, 3, 4, 5, 6],
+            vec![6, 5, 4, 3, 2, 1],
+            vec![5, 5, 5, 5],
+            vec![-3, 0, -7, 2],
+            vec![42],
+            vec![],
+        ]
+    }
+
+    #[test]
+    fn every_sort_agrees_with_std() {
+        for (name, sort) in SORTS {
+            for input in inputs() {
+                let mut actual = input.clone();
+                let mut expected = input.clone();
+                sort(&mut actual);
+                expected.sort();
+
+                assert_eq!(actual, expected, "{} sort on {:?}", name, input);
+            }
+        }
+    }
+
+    #[test]
+    fn sorts_work_on_any_ord_type() {
+        let mut words = vec!["pear", "apple", "fig", "banana"];
+        quick_sort(&mut words);
+        assert_eq!(words, ["apple", "banana", "fig", "pear"]);
+
+        let mut words = vec!["pear", "apple", "fig", "banana"];
+        bubble_sort(&mut words);
+        assert_eq!(words, ["apple", "banana", "fig", "pear"]);
+    }
+
+    #[test]
+    fn iterative_quick_sort_handles_sorted_and_reversed_runs() {
+        // Both would make a last-element pivot recurse 100000 deep
+        let mut ascending: Vec<i32> = (0..100_000).collect();
+        quick_sort_iterative(&mut ascending);
+        assert!(ascending.iter().copied().eq(0..100_000));
+
+        let mut descending: Vec<i32> = (0..100_000).rev().collect();
+        quick_sort_iterative(&mut descending);
+        assert!(descending.iter().copied().eq(0..100_000));
+    }
+
+    #[test]
+    fn median_of_three_moves_the_median_last() {
+        for mut arr in [[1, 2, 3], [3, 2, 1], [2, 3, 1], [1, 3, 2], [2, 1, 3]] {
+            median_of_three(&mut arr);
+            assert_eq!(arr[2], 2, "{:?}", arr);
+        }
+    }
+
+    #[test]
+    fn instrumented_insertion_sort_counts_its_work() {
+        let mut presorted = [1, 2, 3, 4, 5, 6];
+        let stats = insertion_sort_instrumented(&mut presorted);
+        assert_eq!(
+            stats,
+            SortStats {
+                comparisons: 5,
+                swaps: 0
+            }
+        );
+
+        let mut reversed = [6, 5, 4, 3, 2, 1];
+        let stats = insertion_sort_instrumented(&mut reversed);
+        assert_eq!(
+            stats,
+            SortStats {
+                comparisons: 15,
+                swaps: 15
+            }
+        );
+        assert_eq!(reversed, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn instrumented_insertion_sort_sorts_like_the_plain_one() {
+        for input in inputs() {
+            let mut plain = input.clone();
+            let mut counted = input.clone();
+            insertion_sort(&mut plain);
+            insertion_sort_instrumented(&mut counted);
+
+            assert_eq!(plain, counted);
+        }
+
+        let mut empty: [i32; 0] = [];
+        let stats = insertion_sort_instrumented(&mut empty);
+        assert_eq!((stats.comparisons, stats.swaps), (0, 0));
+    }
+}
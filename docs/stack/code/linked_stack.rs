@@ -0,0 +1,100 @@
+#[allow(dead_code)]
+struct Node {
+    data: i32,
+    next: Option<Box<Node>>,
+}
+
+// The top of the stack is the head of the list, so push and pop are just
+// insert_at_head and delete_at_head. The length is cached to keep len() O(1).
+#[allow(dead_code)]
+struct Stack {
+    head: Option<Box<Node>>,
+    len: usize,
+}
+
+#[allow(dead_code)]
+impl Stack {
+    fn new() -> Self {
+        Stack { head: None, len: 0 }
+    }
+
+    fn push(&mut self, data: i32) {
+        self.head = Some(Box::new(Node {
+            data,
+            next: self.head.take(),
+        }));
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<i32> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.data
+        })
+    }
+
+    fn peek(&self) -> Option<&i32> {
+        self.head.as_ref().map(|node| &node.data)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+fn main() {
+    let mut stack = Stack::new();
+
+    stack.push(10); // TOP -> [10]
+    stack.push(20); // TOP -> [20] -> [10]
+    stack.push(30); // TOP -> [30] -> [20] -> [10]
+
+    println!("Peek: {:?}", stack.peek()); // Peek: Some(30)
+    println!("Length: {}", stack.len()); // Length: 3
+
+    println!("{:?}", stack.pop()); // Some(30)
+    println!("{:?}", stack.pop()); // Some(20)
+    println!("{:?}", stack.pop()); // Some(10)
+    println!("{:?}", stack.pop()); // None
+
+    println!("Empty: {}", stack.is_empty()); // Empty: true
+    println!("Length: {}", stack.len()); // Length: 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pop_returns_the_last_push_first() {
+        let mut stack = Stack::new();
+        for data in [1, 2, 3] {
+            stack.push(data);
+        }
+
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.len(), 1);
+
+        stack.push(4);
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(1));
+    }
+
+    #[test]
+    fn empty_stack_has_nothing_to_pop() {
+        let mut stack = Stack::new();
+
+        assert!(stack.is_empty());
+        assert_eq!(stack.peek(), None);
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.len(), 0);
+    }
+}
@@ -0,0 +1,204 @@
+// Both searches work on bytes and return byte offsets of every match, overlapping
+// ones included. An empty pattern matches at every offset, like str::match_indices.
+
+// failure[i] is the length of the longest proper prefix of pattern[..=i] that is also
+// a suffix of it. For "abab", failure is [0, 0, 1, 2].
+fn failure_function(pattern: &[u8]) -> Vec<usize> {
+    let mut failure = vec![0; pattern.len()];
+    let mut k = 0; // Length of the prefix matched so far
+
+    for i in 1..pattern.len() {
+        while k > 0 && pattern[i] != pattern[k] {
+            k = failure[k - 1];
+        }
+        if pattern[i] == pattern[k] {
+            k += 1;
+        }
+
+        failure[i] = k;
+    }
+
+    failure
+}
+
+// Knuth-Morris-Pratt: on a mismatch, the failure function says how much of the pattern
+// is already known to match, so the text is never re-read. O(n + m).
+fn kmp_search(text: &str, pattern: &str) -> Vec<usize> {
+    let text = text.as_bytes();
+    let pattern = pattern.as_bytes();
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+
+    let failure = failure_function(pattern);
+    let mut matches = Vec::new();
+    let mut k = 0;
+
+    for (i, &c) in text.iter().enumerate() {
+        while k > 0 && c != pattern[k] {
+            k = failure[k - 1];
+        }
+        if c == pattern[k] {
+            k += 1;
+        }
+
+        if k == pattern.len() {
+            matches.push(i + 1 - k);
+
+            // Keep the overlap, so "aaa" is found twice in "aaaa"
+            k = failure[k - 1];
+        }
+    }
+
+    matches
+}
+
+const BASE: u64 = 256;
+const MODULUS: u64 = 1_000_000_007;
+
+// Rabin-Karp: compare a hash of each window with the pattern's hash, and only compare
+// the actual bytes when the hashes agree. Sliding the window updates the hash in O(1).
+// Expected O(n + m), O(nm) if every window collides.
+fn rabin_karp(text: &str, pattern: &str) -> Vec<usize> {
+    let text = text.as_bytes();
+    let pattern = pattern.as_bytes();
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+    if m > text.len() {
+        return Vec::new();
+    }
+
+    // BASE^(m - 1), the weight of the byte that leaves the window
+    let mut high = 1;
+    for _ in 1..m {
+        high = high * BASE % MODULUS;
+    }
+
+    let hash = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .fold(0, |h, &b| (h * BASE + b as u64) % MODULUS)
+    };
+
+    let target = hash(pattern);
+    let mut window = hash(&text[..m]);
+    let mut matches = Vec::new();
+
+    for start in 0..=text.len() - m {
+        // Equal hashes can still be different strings, so check before reporting
+        if window == target && &text[start..start + m] == pattern {
+            matches.push(start);
+        }
+
+        if start + m < text.len() {
+            // Remove the leaving byte, shift everything up one place, add the new byte.
+            // MODULUS is added first so the subtraction can't go below zero.
+            let leaving = text[start] as u64 * high % MODULUS;
+            window = (window + MODULUS - leaving) % MODULUS;
+            window = (window * BASE + text[start + m] as u64) % MODULUS;
+        }
+    }
+
+    matches
+}
+
+fn main() {
+    println!("{:?}", failure_function(b"abab")); // [0, 0, 1, 2]
+    println!("{:?}", failure_function(b"aabaaab")); // [0, 1, 0, 1, 2, 2, 3]
+
+    let text = "abracadabra";
+    println!("{:?}", kmp_search(text, "abra")); // [0, 7]
+    println!("{:?}", rabin_karp(text, "abra")); // [0, 7]
+
+    // Overlapping matches
+    println!("{:?}", kmp_search("aaaa", "aaa")); // [0, 1]
+    println!("{:?}", rabin_karp("aaaa", "aaa")); // [0, 1]
+
+    // No match, and a pattern longer than the text
+    println!("{:?}", kmp_search(text, "xyz")); // []
+    println!("{:?}", rabin_karp(text, "xyz")); // []
+    println!("{:?}", rabin_karp("ab", "abc")); // []
+
+    println!("{:?}", kmp_search("abc", "")); // [0, 1, 2, 3]
+    println!("{:?}", rabin_karp("abc", "")); // [0, 1, 2, 3]
+
+    // Both must agree with a brute-force search on a longer text
+    let long = "abaababaabaababaababa".repeat(20);
+    let expected: Vec<usize> = (0..=long.len() - 5)
+        .filter(|&i| &long[i..i + 5] == "abaab")
+        .collect();
+    assert_eq!(kmp_search(&long, "abaab"), expected);
+    assert_eq!(rabin_karp(&long, "abaab"), expected);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every start where pattern occurs, checked one offset at a time
+    fn brute_force(text: &str, pattern: &str) -> Vec<usize> {
+        let (text, pattern) = (text.as_bytes(), pattern.as_bytes());
+        (0..=text.len().saturating_sub(pattern.len()))
+            .filter(|&i| text[i..].starts_with(pattern))
+            .collect()
+    }
+
+    #[test]
+    fn failure_function_tracks_prefix_suffixes() {
+        assert_eq!(failure_function(b"abab"), [0, 0, 1, 2]);
+        assert_eq!(failure_function(b"aabaaab"), [0, 1, 0, 1, 2, 2, 3]);
+        assert_eq!(failure_function(b"aaaa"), [0, 1, 2, 3]);
+        assert_eq!(failure_function(b"abcd"), [0, 0, 0, 0]);
+        assert!(failure_function(b"").is_empty());
+    }
+
+    #[test]
+    fn searches_find_overlapping_matches() {
+        for search in [kmp_search, rabin_karp] {
+            assert_eq!(search("abracadabra", "abra"), [0, 7]);
+            assert_eq!(search("aaaa", "aaa"), [0, 1]);
+            assert_eq!(search("aaaa", "a"), [0, 1, 2, 3]);
+            assert_eq!(search("abc", "abc"), [0]);
+        }
+    }
+
+    #[test]
+    fn searches_handle_missing_and_empty_patterns() {
+        for search in [kmp_search, rabin_karp] {
+            assert!(search("abracadabra", "xyz").is_empty());
+            assert!(search("ab", "abc").is_empty());
+            assert!(search("", "a").is_empty());
+            assert_eq!(search("abc", ""), [0, 1, 2, 3]);
+            assert_eq!(search("", ""), [0]);
+        }
+    }
+
+    #[test]
+    fn searches_return_byte_offsets() {
+        // "é" is two bytes, so "b" starts at byte 3
+        for search in [kmp_search, rabin_karp] {
+            assert_eq!(search("aéb", "b"), [3]);
+            assert_eq!(search("éé", "é"), [0, 2]);
+        }
+    }
+
+    #[test]
+    fn searches_agree_with_brute_force() {
+        let texts = [
+            "abaababaabaababaababa".repeat(20),
+            "mississippi".to_string(),
+            "a".repeat(50),
+        ];
+        let patterns = ["abaab", "aba", "issi", "ss", "aaaa", "b", "mississippi"];
+
+        for text in &texts {
+            for pattern in patterns {
+                let expected = brute_force(text, pattern);
+                assert_eq!(kmp_search(text, pattern), expected);
+                assert_eq!(rabin_karp(text, pattern), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// The start of every suffix of s, sorted so the suffixes are in lexicographic order.
+// Works on bytes, like matching.rs. Once it's built, every occurrence of a pattern is
+// one contiguous block of it, so a binary search finds them all.
+
+// Prefix doubling: first sort the suffixes by their first byte, then by their first 2,
+// 4, 8... bytes. The first 2k bytes of suffix i are its first k bytes followed by the
+// first k bytes of suffix i + k, and both of those are already ranked by the previous
+// round, so each round is a sort by a pair of ranks. O(log n) rounds of an
+// O(n log n) sort makes O(n log^2 n), instead of O(n^2 log n) for comparing whole
+// suffixes.
+fn build_suffix_array(s: &str) -> Vec<usize> {
+    let s = s.as_bytes();
+    let n = s.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    if n == 0 {
+        return sa;
+    }
+
+    // rank[i] orders suffix i by the bytes compared so far, equal prefixes share a rank
+    let mut rank: Vec<usize> = s.iter().map(|&b| b as usize).collect();
+    let mut next_rank = vec![0; n];
+    let mut k = 1;
+
+    loop {
+        // A suffix too short to have a second half sorts first, like a prefix does
+        let key = |i: usize| (rank[i], rank.get(i + k).map_or(0, |&r| r + 1));
+        sa.sort_by_key(|&i| key(i));
+
+        next_rank[sa[0]] = 0;
+        for w in 1..n {
+            let bump = key(sa[w - 1]) != key(sa[w]);
+            next_rank[sa[w]] = next_rank[sa[w - 1]] + bump as usize;
+        }
+        std::mem::swap(&mut rank, &mut next_rank);
+
+        // Every suffix has its own rank, so no longer prefix can change the order
+        if rank[sa[n - 1]] == n - 1 {
+            break;
+        }
+        k *= 2;
+    }
+
+    sa
+}
+
+// The reference: compare the suffixes themselves
+fn brute_force(s: &str) -> Vec<usize> {
+    let mut sa: Vec<usize> = (0..s.len()).collect();
+    sa.sort_by_key(|&i| &s.as_bytes()[i..]);
+    sa
+}
+
+fn main() {
+    // 5 a
+    // 3 ana
+    // 1 anana
+    // 0 banana
+    // 4 na
+    // 2 nana
+    println!("{:?}", build_suffix_array("banana")); // [5, 3, 1, 0, 4, 2]
+
+    // Long runs of the same byte need the most rounds
+    println!("{:?}", build_suffix_array("aaaaa")); // [4, 3, 2, 1, 0]
+
+    println!("{:?}", build_suffix_array("mississippi")); // [10, 7, 4, 1, 0, 9, 8, 6, 3, 5, 2]
+    println!("{:?}", build_suffix_array("a")); // [0]
+    println!("{:?}", build_suffix_array("")); // []
+
+    let long = "abaababaabaababaababa".repeat(20);
+    let words = [
+        "banana",
+        "mississippi",
+        "aaaaa",
+        "abracadabra",
+        "zyxwvu",
+        "abcabcabc",
+        long.as_str(),
+    ];
+    let all_match = words
+        .iter()
+        .all(|w| build_suffix_array(w) == brute_force(w));
+    assert!(all_match);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suffix_array_of_known_words() {
+        assert_eq!(build_suffix_array("banana"), vec![5, 3, 1, 0, 4, 2]);
+        assert_eq!(build_suffix_array("aaaaa"), vec![4, 3, 2, 1, 0]);
+        assert_eq!(
+            build_suffix_array("mississippi"),
+            vec![10, 7, 4, 1, 0, 9, 8, 6, 3, 5, 2]
+        );
+        assert_eq!(build_suffix_array("a"), vec![0]);
+        assert!(build_suffix_array("").is_empty());
+    }
+
+    #[test]
+    fn suffix_array_agrees_with_sorting_whole_suffixes() {
+        let long = "abaababaabaababaababa".repeat(20);
+        let words = [
+            "abracadabra",
+            "zyxwvu",
+            "abcabcabc",
+            "ab",
+            "ba",
+            "aab",
+            long.as_str(),
+        ];
+
+        for word in words {
+            assert_eq!(build_suffix_array(word), brute_force(word), "{}", word);
+        }
+    }
+
+    #[test]
+    fn suffix_array_is_a_permutation() {
+        let mut sa = build_suffix_array("the quick brown fox");
+        sa.sort();
+        assert_eq!(sa, (0..19).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn suffix_array_works_on_bytes() {
+        // "é" is the bytes 0xC3 0xA9, both greater than any ASCII byte, and the suffix
+        // starting inside it (at 0xA9) sorts before the one starting at 0xC3
+        assert_eq!(build_suffix_array("éa"), vec![2, 1, 0]);
+        assert_eq!(build_suffix_array("éa"), brute_force("éa"));
+    }
+}
@@ -0,0 +1,265 @@
+use std::cmp::Ordering;
+
+#[allow(dead_code)]
+struct AvlNode {
+    data: i32,
+    height: usize,
+    left: Option<Box<AvlNode>>,
+    right: Option<Box<AvlNode>>,
+}
+
+#[allow(dead_code)]
+impl AvlNode {
+    fn new(data: i32) -> Box<AvlNode> {
+        Box::new(AvlNode {
+            data,
+            height: 1,
+            left: None,
+            right: None,
+        })
+    }
+
+    // Heights are cached on every node, so reading one is O(1)
+    fn height(root: &Option<Box<AvlNode>>) -> usize {
+        root.as_ref().map_or(0, |node| node.height)
+    }
+
+    fn update_height(node: &mut AvlNode) {
+        node.height = 1 + AvlNode::height(&node.left).max(AvlNode::height(&node.right));
+    }
+
+    // Positive means left-heavy, negative means right-heavy
+    fn balance_factor(node: &AvlNode) -> i64 {
+        AvlNode::height(&node.left) as i64 - AvlNode::height(&node.right) as i64
+    }
+
+    //       y              x
+    //      / \            / \
+    //     x   C   ->     A   y
+    //    / \                / \
+    //   A   B              B   C
+    fn rotate_right(mut y: Box<AvlNode>) -> Box<AvlNode> {
+        let mut x = y.left.take().unwrap();
+
+        y.left = x.right.take();
+        AvlNode::update_height(&mut y);
+
+        x.right = Some(y);
+        AvlNode::update_height(&mut x);
+
+        x
+    }
+
+    //     x                  y
+    //    / \                / \
+    //   A   y     ->       x   C
+    //      / \            / \
+    //     B   C          A   B
+    fn rotate_left(mut x: Box<AvlNode>) -> Box<AvlNode> {
+        let mut y = x.right.take().unwrap();
+
+        x.right = y.left.take();
+        AvlNode::update_height(&mut x);
+
+        y.left = Some(x);
+        AvlNode::update_height(&mut y);
+
+        y
+    }
+
+    fn rebalance(mut node: Box<AvlNode>) -> Box<AvlNode> {
+        AvlNode::update_height(&mut node);
+        let balance = AvlNode::balance_factor(&node);
+
+        if balance > 1 {
+            // Left-Right case: rotate the left child first to turn it into Left-Left
+            if AvlNode::balance_factor(node.left.as_ref().unwrap()) < 0 {
+                node.left = Some(AvlNode::rotate_left(node.left.take().unwrap()));
+            }
+            return AvlNode::rotate_right(node);
+        }
+
+        if balance < -1 {
+            // Right-Left case: rotate the right child first to turn it into Right-Right
+            if AvlNode::balance_factor(node.right.as_ref().unwrap()) > 0 {
+                node.right = Some(AvlNode::rotate_right(node.right.take().unwrap()));
+            }
+            return AvlNode::rotate_left(node);
+        }
+
+        node
+    }
+
+    fn insert(root: Option<Box<AvlNode>>, data: i32) -> Option<Box<AvlNode>> {
+        let mut node = match root {
+            None => return Some(AvlNode::new(data)),
+            Some(node) => node,
+        };
+
+        match data.cmp(&node.data) {
+            Ordering::Less => node.left = AvlNode::insert(node.left.take(), data),
+            Ordering::Greater => node.right = AvlNode::insert(node.right.take(), data),
+            Ordering::Equal => return Some(node),
+        }
+
+        Some(AvlNode::rebalance(node))
+    }
+
+    fn min(node: &AvlNode) -> i32 {
+        let mut current = node;
+
+        while let Some(left) = &current.left {
+            current = left;
+        }
+
+        current.data
+    }
+
+    fn delete(root: Option<Box<AvlNode>>, data: i32) -> Option<Box<AvlNode>> {
+        let mut node = root?;
+
+        match data.cmp(&node.data) {
+            Ordering::Less => node.left = AvlNode::delete(node.left.take(), data),
+            Ordering::Greater => node.right = AvlNode::delete(node.right.take(), data),
+            Ordering::Equal => match (node.left.take(), node.right.take()) {
+                (None, None) => return None,
+                (Some(left), None) => return Some(left),
+                (None, Some(right)) => return Some(right),
+                (Some(left), Some(right)) => {
+                    let successor = AvlNode::min(&right);
+
+                    node.data = successor;
+                    node.left = Some(left);
+                    node.right = AvlNode::delete(Some(right), successor);
+                }
+            },
+        }
+
+        Some(AvlNode::rebalance(node))
+    }
+
+    fn contains(root: &Option<Box<AvlNode>>, data: i32) -> bool {
+        let mut current = root;
+
+        while let Some(node) = current {
+            current = match data.cmp(&node.data) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+
+        false
+    }
+
+    fn in_order(root: &Option<Box<AvlNode>>) -> Vec<i32> {
+        let mut result = Vec::new();
+        AvlNode::in_order_into(root, &mut result);
+        result
+    }
+
+    fn in_order_into(root: &Option<Box<AvlNode>>, result: &mut Vec<i32>) {
+        if let Some(node) = root {
+            AvlNode::in_order_into(&node.left, result);
+            result.push(node.data);
+            AvlNode::in_order_into(&node.right, result);
+        }
+    }
+}
+
+fn main() {
+    let mut root = None;
+
+    for data in [10, 20, 30] {
+        root = AvlNode::insert(root, data); // 30 makes the tree right-heavy, so it rotates left
+    }
+    //     20
+    //    /  \
+    //   10  30
+    println!("Root: {}", root.as_ref().unwrap().data); // Root: 20
+
+    for data in [25, 28] {
+        root = AvlNode::insert(root, data); // 28 triggers a Right-Left rotation at 30
+    }
+    println!("{:?}", AvlNode::in_order(&root)); // [10, 20, 25, 28, 30]
+
+    root = AvlNode::delete(root, 10); // Root becomes right-heavy and rotates left
+    println!("Root: {}", root.as_ref().unwrap().data); // Root: 28
+    println!("{:?}", AvlNode::in_order(&root)); // [20, 25, 28, 30]
+    println!("Contains 28: {}", AvlNode::contains(&root, 28)); // Contains 28: true
+    println!("Contains 10: {}", AvlNode::contains(&root, 10)); // Contains 10: false
+
+    // Ascending inserts are the worst case for a plain BST (height 1000)
+    let mut ascending = None;
+    for data in 1..=1000 {
+        ascending = AvlNode::insert(ascending, data);
+    }
+
+    // An AVL tree's height is at most about 1.44 * log2(n + 2)
+    let height = AvlNode::height(&ascending);
+    let bound = 1.44 * (1000.0_f64 + 2.0).log2();
+    println!("Height: {}", height); // Height: 10
+    assert!((height as f64) <= bound);
+
+    for data in 1..=500 {
+        ascending = AvlNode::delete(ascending, data);
+    }
+    let remaining = AvlNode::in_order(&ascending);
+    println!("Height: {}", AvlNode::height(&ascending)); // Height: 10
+    assert_eq!(remaining, (501..=1000).collect::<Vec<_>>());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Checks every cached height and balance factor, returning the real height
+    fn checked_height(root: &Option<Box<AvlNode>>) -> usize {
+        let Some(node) = root else {
+            return 0;
+        };
+
+        let left = checked_height(&node.left);
+        let right = checked_height(&node.right);
+        assert!(left.abs_diff(right) <= 1, "{} is unbalanced", node.data);
+        assert_eq!(node.height, 1 + left.max(right));
+
+        node.height
+    }
+
+    #[test]
+    fn every_rotation_case_rebalances() {
+        // Left-Left, Right-Right, Left-Right and Right-Left
+        for order in [[30, 20, 10], [10, 20, 30], [30, 10, 20], [10, 30, 20]] {
+            let mut root = None;
+            for data in order {
+                root = AvlNode::insert(root, data);
+            }
+
+            assert_eq!(root.as_ref().unwrap().data, 20);
+            assert_eq!(checked_height(&root), 2);
+        }
+    }
+
+    #[test]
+    fn stays_balanced_through_inserts_and_deletes() {
+        let mut root = None;
+        for data in 1..=1000 {
+            root = AvlNode::insert(root, data);
+            checked_height(&root);
+        }
+        assert_eq!(AvlNode::height(&root), 10);
+
+        for data in (1..=1000).step_by(2) {
+            root = AvlNode::delete(root, data);
+            checked_height(&root);
+        }
+
+        assert_eq!(
+            AvlNode::in_order(&root),
+            (2..=1000).step_by(2).collect::<Vec<_>>()
+        );
+        assert!(!AvlNode::contains(&root, 1));
+        assert!(AvlNode::contains(&root, 2));
+    }
+}
@@ -0,0 +1,1014 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[allow(dead_code)]
+#[derive(PartialEq)]
+struct BstNode<T> {
+    data: T,
+    left: Option<Box<BstNode<T>>>,
+    right: Option<Box<BstNode<T>>>,
+}
+
+#[derive(Debug, PartialEq)]
+enum DeserializeError {
+    InvalidToken(String),
+    OutOfOrder(String),
+    UnexpectedEnd,
+    TrailingInput,
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeserializeError::InvalidToken(token) => write!(f, "Invalid token {:?}", token),
+            DeserializeError::OutOfOrder(token) => {
+                write!(f, "Value {:?} breaks the search tree order", token)
+            }
+            DeserializeError::UnexpectedEnd => write!(f, "Input ended before the tree did"),
+            DeserializeError::TrailingInput => write!(f, "Input continues after the tree"),
+        }
+    }
+}
+
+// Every comparison goes through Ord::cmp, so any totally ordered type works as a key
+#[allow(dead_code)]
+impl<T: Ord> BstNode<T> {
+    fn new(data: T) -> Box<BstNode<T>> {
+        Box::new(BstNode {
+            data,
+            left: None,
+            right: None,
+        })
+    }
+
+    fn insert(root: Option<Box<BstNode<T>>>, data: T) -> Option<Box<BstNode<T>>> {
+        match root {
+            None => Some(BstNode::new(data)),
+            Some(mut node) => {
+                match data.cmp(&node.data) {
+                    Ordering::Less => node.left = BstNode::insert(node.left.take(), data),
+                    Ordering::Greater => node.right = BstNode::insert(node.right.take(), data),
+                    Ordering::Equal => {} // Duplicates are ignored
+                }
+
+                Some(node)
+            }
+        }
+    }
+
+    fn contains(root: &Option<Box<BstNode<T>>>, data: &T) -> bool {
+        let mut current = root;
+
+        while let Some(node) = current {
+            current = match data.cmp(&node.data) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+
+        false
+    }
+
+    fn min(node: &BstNode<T>) -> &T {
+        let mut current = node;
+
+        while let Some(left) = &current.left {
+            current = left;
+        }
+
+        &current.data
+    }
+
+    // Unlinks the smallest node of a subtree, returning its data and what's left of the subtree
+    fn take_min(mut node: Box<BstNode<T>>) -> (T, Option<Box<BstNode<T>>>) {
+        match node.left.take() {
+            None => (node.data, node.right),
+            Some(left) => {
+                let (min, rest) = BstNode::take_min(left);
+                node.left = rest;
+
+                (min, Some(node))
+            }
+        }
+    }
+
+    fn delete(root: Option<Box<BstNode<T>>>, data: &T) -> Option<Box<BstNode<T>>> {
+        let mut node = root?;
+
+        match data.cmp(&node.data) {
+            Ordering::Less => node.left = BstNode::delete(node.left.take(), data),
+            Ordering::Greater => node.right = BstNode::delete(node.right.take(), data),
+            Ordering::Equal => match (node.left.take(), node.right.take()) {
+                // Leaf: just remove it
+                (None, None) => return None,
+                // One child: the child takes the node's place
+                (Some(left), None) => return Some(left),
+                (None, Some(right)) => return Some(right),
+                // Two children: move the in-order successor up, out of the right subtree.
+                // Taking it out rather than copying it means T doesn't need to be Clone.
+                (Some(left), Some(right)) => {
+                    let (successor, rest) = BstNode::take_min(right);
+
+                    node.data = successor;
+                    node.left = Some(left);
+                    node.right = rest;
+                }
+            },
+        }
+
+        Some(node)
+    }
+
+    // Walk down while a and b are on the same side of the current node. The first node
+    // that separates them (or is one of them) is their lowest common ancestor, as long
+    // as both are actually in the tree below it.
+    fn lowest_common_ancestor<'a>(root: &'a BstNode<T>, a: &T, b: &T) -> Option<&'a T> {
+        let mut split = root;
+
+        loop {
+            let next = match (a.cmp(&split.data), b.cmp(&split.data)) {
+                (Ordering::Less, Ordering::Less) => split.left.as_deref(),
+                (Ordering::Greater, Ordering::Greater) => split.right.as_deref(),
+                _ => break,
+            };
+
+            split = next?;
+        }
+
+        let found = |x: &T| {
+            let mut current = Some(split);
+
+            while let Some(node) = current {
+                current = match x.cmp(&node.data) {
+                    Ordering::Less => node.left.as_deref(),
+                    Ordering::Greater => node.right.as_deref(),
+                    Ordering::Equal => return true,
+                };
+            }
+
+            false
+        };
+
+        if found(a) && found(b) {
+            Some(&split.data)
+        } else {
+            None
+        }
+    }
+
+    // Every value in [lo, hi], in sorted order. An in-order walk that skips a left
+    // subtree when the node is already below lo (everything there is smaller still),
+    // and a right subtree when the node is already above hi.
+    fn range(&self, lo: &T, hi: &T) -> Vec<&T> {
+        let mut result = Vec::new();
+        self.range_into(lo, hi, &mut result);
+        result
+    }
+
+    fn range_into<'a>(&'a self, lo: &T, hi: &T, result: &mut Vec<&'a T>) {
+        if let (Some(left), Ordering::Greater) = (&self.left, self.data.cmp(lo)) {
+            left.range_into(lo, hi, result);
+        }
+
+        if self.data.cmp(lo) != Ordering::Less && self.data.cmp(hi) != Ordering::Greater {
+            result.push(&self.data);
+        }
+
+        if let (Some(right), Ordering::Less) = (&self.right, self.data.cmp(hi)) {
+            right.range_into(lo, hi, result);
+        }
+    }
+
+    // Counted in nodes, so a single node has height 1 and an empty subtree has height 0
+    fn height(&self) -> usize {
+        let left = self.left.as_ref().map_or(0, |node| node.height());
+        let right = self.right.as_ref().map_or(0, |node| node.height());
+
+        1 + left.max(right)
+    }
+
+    fn is_balanced(&self) -> bool {
+        let left = BstNode::balanced_height(&self.left);
+        let right = BstNode::balanced_height(&self.right);
+
+        matches!((left, right), (Some(l), Some(r)) if l.abs_diff(r) <= 1)
+    }
+
+    // Post-order: returns the subtree's height, or None as soon as any subtree is unbalanced
+    fn balanced_height(root: &Option<Box<BstNode<T>>>) -> Option<usize> {
+        match root {
+            None => Some(0),
+            Some(node) => {
+                let left = BstNode::balanced_height(&node.left)?;
+                let right = BstNode::balanced_height(&node.right)?;
+
+                if left.abs_diff(right) > 1 {
+                    return None;
+                }
+
+                Some(1 + left.max(right))
+            }
+        }
+    }
+
+    fn pre_order(root: &Option<Box<BstNode<T>>>) -> Vec<&T> {
+        let mut result = Vec::new();
+        BstNode::pre_order_into(root, &mut result);
+        result
+    }
+
+    fn pre_order_into<'a>(root: &'a Option<Box<BstNode<T>>>, result: &mut Vec<&'a T>) {
+        if let Some(node) = root {
+            result.push(&node.data);
+            BstNode::pre_order_into(&node.left, result);
+            BstNode::pre_order_into(&node.right, result);
+        }
+    }
+
+    fn in_order(root: &Option<Box<BstNode<T>>>) -> Vec<&T> {
+        let mut result = Vec::new();
+        BstNode::in_order_into(root, &mut result);
+        result
+    }
+
+    fn in_order_into<'a>(root: &'a Option<Box<BstNode<T>>>, result: &mut Vec<&'a T>) {
+        if let Some(node) = root {
+            BstNode::in_order_into(&node.left, result);
+            result.push(&node.data);
+            BstNode::in_order_into(&node.right, result);
+        }
+    }
+
+    // Same order as in_order, but with an explicit stack instead of recursion so a
+    // degenerate (linked-list-shaped) tree can't overflow the call stack
+    fn in_order_iterative(&self) -> Vec<&T> {
+        let mut result = Vec::new();
+        let mut stack: Vec<&BstNode<T>> = Vec::new();
+        let mut current = Some(self);
+
+        while current.is_some() || !stack.is_empty() {
+            // Walk as far left as possible, remembering the path
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left.as_deref();
+            }
+
+            // Visit the leftmost unvisited node, then go right
+            let node = stack.pop().unwrap();
+            result.push(&node.data);
+            current = node.right.as_deref();
+        }
+
+        result
+    }
+
+    // In-order without recursion or a stack of nodes. This is link reversal, not Morris
+    // traversal. Morris threads each node's in-order predecessor back up to it, but a
+    // thread is a second pointer to a node that's already owned, which a Box can't be.
+    // Here, on the way down, the child link that was followed is pointed back at the
+    // parent, and on the way up it's put back. Which of the two links that was can't
+    // be read off the tree (the data needn't be in order, and both links are in use),
+    // so one bit per level records it: O(h) bits, where Schorr-Waite would keep the
+    // bit in each node. The tree is borrowed mutably while it's rewired, and comes back
+    // exactly as it was.
+    fn link_reversal_in_order(root: &mut Option<Box<BstNode<T>>>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut result = Vec::new();
+        let Some(mut node) = root.take() else {
+            return result;
+        };
+        // The path back to the root, through the reversed links, and for each node on
+        // it whether its left link is the reversed one
+        let mut back: Option<Box<BstNode<T>>> = None;
+        let mut went_left: Vec<bool> = Vec::new();
+
+        'descend: loop {
+            while let Some(left) = node.left.take() {
+                node.left = back;
+                back = Some(node);
+                went_left.push(true);
+                node = left;
+            }
+
+            // Everything left of node has been visited
+            loop {
+                result.push(node.data.clone());
+
+                if let Some(right) = node.right.take() {
+                    node.right = back;
+                    back = Some(node);
+                    went_left.push(false);
+                    node = right;
+                    continue 'descend;
+                }
+
+                // node's subtree is finished and restored, so climb until arriving at a
+                // parent from its left, which is the next node to visit
+                loop {
+                    let (Some(mut parent), Some(from_left)) = (back.take(), went_left.pop()) else {
+                        *root = Some(node);
+                        return result;
+                    };
+
+                    if from_left {
+                        back = parent.left.replace(node);
+                    } else {
+                        back = parent.right.replace(node);
+                    }
+                    node = parent;
+
+                    if from_left {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn post_order(root: &Option<Box<BstNode<T>>>) -> Vec<&T> {
+        let mut result = Vec::new();
+        BstNode::post_order_into(root, &mut result);
+        result
+    }
+
+    fn post_order_into<'a>(root: &'a Option<Box<BstNode<T>>>, result: &mut Vec<&'a T>) {
+        if let Some(node) = root {
+            BstNode::post_order_into(&node.left, result);
+            BstNode::post_order_into(&node.right, result);
+            result.push(&node.data);
+        }
+    }
+
+    // Pre-order, with # standing in for every missing child, so "2,1,#,#,#" is a 2 with
+    // a left child 1. The markers pin down the exact shape, not just the values.
+    fn serialize(root: &Option<Box<BstNode<T>>>) -> String
+    where
+        T: Display,
+    {
+        let mut tokens = Vec::new();
+        BstNode::serialize_into(root, &mut tokens);
+        tokens.join(",")
+    }
+
+    fn serialize_into(root: &Option<Box<BstNode<T>>>, tokens: &mut Vec<String>)
+    where
+        T: Display,
+    {
+        match root {
+            None => tokens.push("#".to_string()),
+            Some(node) => {
+                tokens.push(node.data.to_string());
+                BstNode::serialize_into(&node.left, tokens);
+                BstNode::serialize_into(&node.right, tokens);
+            }
+        }
+    }
+
+    // Reads the tokens back in the same pre-order: a value, then its whole left
+    // subtree, then its whole right subtree. The input has to be exactly one tree: a
+    // token that isn't # or a value, running out early, or anything left over is an error.
+    // So is a value on the wrong side of an ancestor, or a repeated one: every query
+    // on the tree relies on the order, so a tree that breaks it is never built.
+    fn deserialize(data: &str) -> Result<Option<Box<BstNode<T>>>, DeserializeError>
+    where
+        T: FromStr,
+    {
+        let mut tokens = data.split(',');
+        let root = BstNode::deserialize_from(&mut tokens, None, None)?;
+
+        match tokens.next() {
+            None => Ok(root),
+            Some(_) => Err(DeserializeError::TrailingInput),
+        }
+    }
+
+    // Every value in this subtree has to be strictly between lo and hi, the closest
+    // ancestors it went left and right of. None means there's no bound on that side.
+    fn deserialize_from<'a, I>(
+        tokens: &mut I,
+        lo: Option<&T>,
+        hi: Option<&T>,
+    ) -> Result<Option<Box<BstNode<T>>>, DeserializeError>
+    where
+        T: FromStr,
+        I: Iterator<Item = &'a str>,
+    {
+        let token = tokens.next().ok_or(DeserializeError::UnexpectedEnd)?;
+        if token == "#" {
+            return Ok(None);
+        }
+
+        let data: T = token
+            .parse()
+            .map_err(|_| DeserializeError::InvalidToken(token.to_string()))?;
+
+        let above_lo = lo.is_none_or(|lo| data.cmp(lo) == Ordering::Greater);
+        let below_hi = hi.is_none_or(|hi| data.cmp(hi) == Ordering::Less);
+        if !above_lo || !below_hi {
+            return Err(DeserializeError::OutOfOrder(token.to_string()));
+        }
+
+        let left = BstNode::deserialize_from(tokens, lo, Some(&data))?;
+        let right = BstNode::deserialize_from(tokens, Some(&data), hi)?;
+
+        Ok(Some(Box::new(BstNode { data, left, right })))
+    }
+
+    // The tree turned 90 degrees counter-clockwise: one node per line, indented four
+    // spaces per level, with the right subtree above its parent and the left one below.
+    // Tilting your head to the left shows the usual picture.
+    fn pretty_print(root: &Option<Box<BstNode<T>>>) -> String
+    where
+        T: Display,
+    {
+        let mut out = String::new();
+        BstNode::pretty_print_into(root, 0, &mut out);
+        out
+    }
+
+    fn pretty_print_into(root: &Option<Box<BstNode<T>>>, depth: usize, out: &mut String)
+    where
+        T: Display,
+    {
+        if let Some(node) = root {
+            BstNode::pretty_print_into(&node.right, depth + 1, out);
+            out.push_str(&format!("{}{}\n", "    ".repeat(depth), node.data));
+            BstNode::pretty_print_into(&node.left, depth + 1, out);
+        }
+    }
+
+    fn level_order(root: &Option<Box<BstNode<T>>>) -> Vec<&T> {
+        let mut result = Vec::new();
+        let mut queue = VecDeque::new();
+
+        if let Some(node) = root {
+            queue.push_back(node);
+        }
+
+        while let Some(node) = queue.pop_front() {
+            result.push(&node.data);
+
+            if let Some(left) = &node.left {
+                queue.push_back(left);
+            }
+            if let Some(right) = &node.right {
+                queue.push_back(right);
+            }
+        }
+
+        result
+    }
+}
+
+fn main() {
+    let mut root = None;
+
+    for data in [50, 30, 70, 20, 40, 60, 80] {
+        root = BstNode::insert(root, data);
+    }
+    //         50
+    //       /    \
+    //     30      70
+    //    /  \    /  \
+    //   20  40  60  80
+
+    println!("{:?}", BstNode::pre_order(&root)); // [50, 30, 20, 40, 70, 60, 80]
+    println!("{:?}", BstNode::in_order(&root)); // [20, 30, 40, 50, 60, 70, 80]
+    println!("{:?}", BstNode::post_order(&root)); // [20, 40, 30, 60, 80, 70, 50]
+    println!("{:?}", BstNode::level_order(&root)); // [50, 30, 70, 20, 40, 60, 80]
+
+    print!("{}", BstNode::pretty_print(&root));
+    //         80
+    //     70
+    //         60
+    // 50
+    //         40
+    //     30
+    //         20
+
+    let mut small = None;
+    for data in [2, 1, 3, 4] {
+        small = BstNode::insert(small, data);
+    }
+    let rendered = BstNode::pretty_print(&small);
+    assert_eq!(rendered, "        4\n    3\n2\n    1\n");
+    println!("{:?}", BstNode::<i32>::pretty_print(&None)); // ""
+
+    let shape = BstNode::serialize(&root);
+    println!("{:?}", BstNode::link_reversal_in_order(&mut root)); // [20, 30, 40, 50, 60, 70, 80]
+    assert_eq!(BstNode::serialize(&root), shape);
+
+    let top = root.as_ref().unwrap();
+    println!("{:?}", BstNode::lowest_common_ancestor(top, &20, &40)); // Some(30)
+    println!("{:?}", BstNode::lowest_common_ancestor(top, &20, &80)); // Some(50)
+    println!("{:?}", BstNode::lowest_common_ancestor(top, &70, &60)); // Some(70), one of the two
+    println!("{:?}", BstNode::lowest_common_ancestor(top, &20, &45)); // None, 45 isn't there
+    println!("{:?}", BstNode::lowest_common_ancestor(top, &5, &10)); // None
+
+    println!("{:?}", top.range(&0, &100)); // [20, 30, 40, 50, 60, 70, 80]
+    println!("{:?}", top.range(&35, &65)); // [40, 50, 60]
+    println!("{:?}", top.range(&41, &49)); // []
+
+    let full = root.as_ref().unwrap();
+    println!("Height: {}", full.height()); // Height: 3
+    println!("Balanced: {}", full.is_balanced()); // Balanced: true
+
+    println!("Contains 60: {}", BstNode::contains(&root, &60)); // Contains 60: true
+    println!("Contains 65: {}", BstNode::contains(&root, &65)); // Contains 65: false
+
+    root = BstNode::delete(root, &20); // Leaf
+    root = BstNode::delete(root, &30); // One child (40)
+    println!("{:?}", BstNode::in_order(&root)); // [40, 50, 60, 70, 80]
+
+    root = BstNode::delete(root, &50); // Two children, replaced by successor 60
+    println!("Root: {}", root.as_ref().unwrap().data); // Root: 60
+    println!("{:?}", BstNode::in_order(&root)); // [40, 60, 70, 80]
+
+    let mut shuffled = None;
+    for data in [15, 3, 42, 8, 23, 4, 16, 99, 1] {
+        shuffled = BstNode::insert(shuffled, data);
+    }
+    println!("{:?}", BstNode::in_order(&shuffled)); // [1, 3, 4, 8, 15, 16, 23, 42, 99]
+    println!("{:?}", shuffled.as_ref().unwrap().in_order_iterative()); // [1, 3, 4, 8, 15, 16, 23, 42, 99]
+
+    let encoded = BstNode::serialize(&shuffled);
+    println!("{}", encoded); // 15,3,1,#,#,8,4,#,#,#,42,23,16,#,#,#,99,#,#
+    let decoded: Option<Box<BstNode<i32>>> = BstNode::deserialize(&encoded).unwrap();
+    assert!(decoded == shuffled);
+    println!("{:?}", BstNode::pre_order(&decoded)); // [15, 3, 1, 8, 4, 42, 23, 16, 99]
+    println!("{:?}", BstNode::level_order(&decoded)); // [15, 3, 42, 1, 8, 23, 99, 4, 16]
+
+    let mut rewired = decoded;
+    let recursive: Vec<i32> = BstNode::in_order(&shuffled).into_iter().copied().collect();
+    assert_eq!(BstNode::link_reversal_in_order(&mut rewired), recursive);
+    assert!(rewired == shuffled);
+
+    let encoded = BstNode::<i32>::serialize(&None);
+    println!("{}", encoded); // #
+    let nothing: Option<Box<BstNode<i32>>> = BstNode::deserialize(&encoded).unwrap();
+    assert!(nothing.is_none());
+
+    for bad in ["2,x,#,#", "2,3,#,#,#", "2,1,#,#", "2,#,#,3"] {
+        if let Err(err) = BstNode::<i32>::deserialize(bad) {
+            println!("{}", err);
+        }
+    }
+    // Invalid token "x"
+    // Value "3" breaks the search tree order
+    // Input ended before the tree did
+    // Input continues after the tree
+
+    let mut names = None;
+    for name in ["mango", "apple", "peach", "cherry", "banana", "apricot"] {
+        names = BstNode::insert(names, name.to_string());
+    }
+    let sorted_names = BstNode::in_order(&names);
+    println!("{:?}", sorted_names); // ["apple", "apricot", "banana", "cherry", "mango", "peach"]
+    assert!(sorted_names.windows(2).all(|w| w[0] < w[1]));
+    println!("{}", BstNode::contains(&names, &"cherry".to_string())); // true
+
+    names = BstNode::delete(names, &"mango".to_string()); // Root, two children
+    println!("{:?}", BstNode::level_order(&names)); // ["peach", "apple", "cherry", "banana", "apricot"]
+
+    let mut chain = None;
+    for data in [1, 2, 3, 4] {
+        chain = BstNode::insert(chain, data);
+    }
+    let chain = chain.unwrap();
+    println!("Height: {}", chain.height()); // Height: 4
+    println!("Balanced: {}", chain.is_balanced()); // Balanced: false
+
+    let single = BstNode::new(7);
+    println!("Height: {}", single.height()); // Height: 1
+    println!("Balanced: {}", single.is_balanced()); // Balanced: true
+
+    // Right-leaning chain 0 -> 1 -> ... -> 99999, built bottom-up to avoid recursive inserts
+    let mut skewed = None;
+    for data in (0..100_000).rev() {
+        skewed = Some(Box::new(BstNode {
+            data,
+            left: None,
+            right: skewed,
+        }));
+    }
+
+    let visited = skewed.as_ref().unwrap().in_order_iterative();
+    println!("Visited: {:?}", (visited.len(), visited.last())); // Visited: (100000, Some(99999))
+
+    // 100000 levels deep, but the climb back up is a loop too
+    let walked = BstNode::link_reversal_in_order(&mut skewed);
+    assert!(walked.into_iter().eq(0..100_000));
+    println!("{:?}", BstNode::<i32>::link_reversal_in_order(&mut None)); // []
+
+    // Tear the chain down iteratively too, dropping it directly would recurse 100000 deep
+    while let Some(mut node) = skewed {
+        skewed = node.right.take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(items: &[i32]) -> Option<Box<BstNode<i32>>> {
+        items
+            .iter()
+            .fold(None, |root, &data| BstNode::insert(root, data))
+    }
+
+    fn sorted(root: &Option<Box<BstNode<i32>>>) -> Vec<i32> {
+        BstNode::in_order(root).into_iter().copied().collect()
+    }
+
+    #[test]
+    fn contains_finds_only_inserted_values() {
+        let root = tree(&[50, 30, 70, 30]);
+
+        assert!(BstNode::contains(&root, &30));
+        assert!(BstNode::contains(&root, &70));
+        assert!(!BstNode::contains(&root, &40));
+        assert!(!BstNode::contains(&None, &1));
+        assert_eq!(sorted(&root), vec![30, 50, 70]);
+    }
+
+    #[test]
+    fn delete_handles_leaves_single_children_and_two_children() {
+        let root = tree(&[50, 30, 70, 20, 40, 60, 80, 35]);
+
+        let root = BstNode::delete(root, &20); // Leaf
+        assert_eq!(sorted(&root), vec![30, 35, 40, 50, 60, 70, 80]);
+
+        let root = BstNode::delete(root, &40); // One child (35)
+        assert_eq!(sorted(&root), vec![30, 35, 50, 60, 70, 80]);
+
+        let root = BstNode::delete(root, &50); // Two children
+        assert_eq!(root.as_ref().map(|node| node.data), Some(60));
+        assert_eq!(sorted(&root), vec![30, 35, 60, 70, 80]);
+
+        let root = BstNode::delete(root, &99); // Missing
+        assert_eq!(sorted(&root), vec![30, 35, 60, 70, 80]);
+    }
+
+    #[test]
+    fn traversals_visit_in_their_own_orders() {
+        //         50
+        //       /    \
+        //     30      70
+        //    /  \       \
+        //   20  40      80
+        let root = tree(&[50, 30, 70, 20, 40, 80]);
+
+        assert_eq!(BstNode::pre_order(&root), [&50, &30, &20, &40, &70, &80]);
+        assert_eq!(BstNode::in_order(&root), [&20, &30, &40, &50, &70, &80]);
+        assert_eq!(BstNode::post_order(&root), [&20, &40, &30, &80, &70, &50]);
+        assert_eq!(BstNode::level_order(&root), [&50, &30, &70, &20, &40, &80]);
+    }
+
+    #[test]
+    fn traversals_of_an_empty_tree_are_empty() {
+        assert!(BstNode::<i32>::pre_order(&None).is_empty());
+        assert!(BstNode::<i32>::in_order(&None).is_empty());
+        assert!(BstNode::<i32>::post_order(&None).is_empty());
+        assert!(BstNode::<i32>::level_order(&None).is_empty());
+    }
+
+    #[test]
+    fn iterative_in_order_matches_the_recursive_one() {
+        let root = tree(&[15, 3, 42, 8, 23, 4, 16, 99, 1]);
+
+        let node = root.as_ref().unwrap();
+        assert_eq!(node.in_order_iterative(), BstNode::in_order(&root));
+    }
+
+    #[test]
+    fn iterative_in_order_handles_a_deep_chain() {
+        let mut chain = None;
+        for data in (0..100_000).rev() {
+            chain = Some(Box::new(BstNode {
+                data,
+                left: None,
+                right: chain,
+            }));
+        }
+
+        let visited = chain.as_ref().unwrap().in_order_iterative();
+        assert!(visited.into_iter().copied().eq(0..100_000));
+
+        while let Some(mut node) = chain {
+            chain = node.right.take();
+        }
+    }
+
+    #[test]
+    fn height_counts_nodes_on_the_longest_path() {
+        assert_eq!(BstNode::new(7).height(), 1);
+        assert_eq!(tree(&[2, 1, 3]).unwrap().height(), 2);
+        assert_eq!(tree(&[1, 2, 3, 4]).unwrap().height(), 4);
+    }
+
+    #[test]
+    fn is_balanced_checks_every_subtree() {
+        assert!(BstNode::new(7).is_balanced());
+        assert!(tree(&[50, 30, 70, 20]).unwrap().is_balanced());
+        assert!(!tree(&[1, 2, 3]).unwrap().is_balanced());
+
+        // The root's subtrees have heights 3 and 3, but 30's don't (2 and 0)
+        let lopsided = tree(&[50, 30, 70, 20, 10, 60, 80, 90]).unwrap();
+        assert!(!lopsided.is_balanced());
+    }
+
+    #[test]
+    fn string_keys_are_ordered_lexicographically() {
+        let mut names = None;
+        for name in ["mango", "apple", "peach", "cherry", "banana", "apricot"] {
+            names = BstNode::insert(names, name.to_string());
+        }
+
+        let in_order: Vec<&str> = BstNode::in_order(&names)
+            .into_iter()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            in_order,
+            ["apple", "apricot", "banana", "cherry", "mango", "peach"]
+        );
+        assert!(BstNode::contains(&names, &"cherry".to_string()));
+        assert!(!BstNode::contains(&names, &"grape".to_string()));
+    }
+
+    #[test]
+    fn string_keys_support_delete_and_duplicates() {
+        let mut names = None;
+        for name in ["mango", "apple", "peach", "mango", "apple"] {
+            names = BstNode::insert(names, name.to_string());
+        }
+        assert_eq!(BstNode::in_order(&names).len(), 3);
+
+        names = BstNode::delete(names, &"mango".to_string());
+        assert_eq!(names.as_ref().unwrap().data, "peach");
+        assert_eq!(BstNode::in_order(&names), ["apple", "peach"]);
+    }
+
+    #[test]
+    fn any_ord_type_works_as_a_key() {
+        let mut pairs = None;
+        for pair in [(2, 'b'), (1, 'z'), (2, 'a')] {
+            pairs = BstNode::insert(pairs, pair);
+        }
+        assert_eq!(BstNode::in_order(&pairs), [&(1, 'z'), &(2, 'a'), &(2, 'b')]);
+    }
+
+    #[test]
+    fn serialize_marks_every_missing_child() {
+        let root = tree(&[15, 3, 42, 8, 23, 4, 16, 99, 1]);
+        assert_eq!(
+            BstNode::serialize(&root),
+            "15,3,1,#,#,8,4,#,#,#,42,23,16,#,#,#,99,#,#"
+        );
+        assert_eq!(BstNode::serialize(&tree(&[2, 1])), "2,1,#,#,#");
+        assert_eq!(BstNode::<i32>::serialize(&None), "#");
+    }
+
+    #[test]
+    fn deserialize_rebuilds_the_same_shape() {
+        for items in [
+            &[15, 3, 42, 8, 23, 4, 16, 99, 1][..],
+            &[1, 2, 3],
+            &[3, 2, 1],
+            &[7],
+            &[],
+        ] {
+            let root = tree(items);
+            let decoded = BstNode::deserialize(&BstNode::serialize(&root)).unwrap();
+            assert!(decoded == root);
+        }
+    }
+
+    #[test]
+    fn deserialize_rejects_tokens_that_are_not_values() {
+        let bad = |data: &str| BstNode::<i32>::deserialize(data).err();
+
+        assert_eq!(
+            bad("2,x,#,#"),
+            Some(DeserializeError::InvalidToken("x".to_string()))
+        );
+        assert_eq!(bad(""), Some(DeserializeError::InvalidToken(String::new())));
+        assert_eq!(
+            bad("2, 1,#,#,#"),
+            Some(DeserializeError::InvalidToken(" 1".to_string()))
+        );
+    }
+
+    #[test]
+    fn deserialize_rejects_trees_that_break_the_order() {
+        let bad = |data: &str| BstNode::<i32>::deserialize(data).err();
+        let out_of_order = |token: &str| Some(DeserializeError::OutOfOrder(token.to_string()));
+
+        // Children on the wrong side
+        assert_eq!(bad("1,2,#,#,#"), out_of_order("2"));
+        assert_eq!(bad("5,#,3,#,#"), out_of_order("3"));
+
+        // Duplicates, which insert never creates
+        assert_eq!(bad("1,1,#,#,#"), out_of_order("1"));
+        assert_eq!(bad("1,#,1,#,#"), out_of_order("1"));
+        assert_eq!(bad("5,3,#,#,3,#,#"), out_of_order("3"));
+
+        // Fine next to its parent, but on the wrong side of the grandparent
+        assert_eq!(bad("10,5,#,12,#,#,#"), out_of_order("12"));
+        assert_eq!(bad("10,#,15,8,#,#,#"), out_of_order("8"));
+    }
+
+    #[test]
+    fn deserialize_rejects_a_tree_that_ends_early_or_runs_on() {
+        let bad = |data: &str| BstNode::<i32>::deserialize(data).err();
+
+        assert_eq!(bad("2"), Some(DeserializeError::UnexpectedEnd));
+        assert_eq!(bad("2,1,#,#"), Some(DeserializeError::UnexpectedEnd));
+        assert_eq!(bad("#,#"), Some(DeserializeError::TrailingInput));
+        assert_eq!(bad("2,#,#,3"), Some(DeserializeError::TrailingInput));
+    }
+
+    #[test]
+    fn deserialize_works_for_string_keys() {
+        let mut names = None;
+        for name in ["mango", "apple", "peach"] {
+            names = BstNode::insert(names, name.to_string());
+        }
+
+        let encoded = BstNode::serialize(&names);
+        assert_eq!(encoded, "mango,apple,#,#,peach,#,#");
+        assert!(BstNode::deserialize(&encoded).unwrap() == names);
+    }
+
+    #[test]
+    fn lowest_common_ancestor_is_where_the_paths_split() {
+        let root = tree(&[50, 30, 70, 20, 40, 60, 80]);
+        let top = root.as_ref().unwrap();
+
+        assert_eq!(BstNode::lowest_common_ancestor(top, &20, &40), Some(&30));
+        assert_eq!(BstNode::lowest_common_ancestor(top, &40, &20), Some(&30));
+        assert_eq!(BstNode::lowest_common_ancestor(top, &20, &80), Some(&50));
+        assert_eq!(BstNode::lowest_common_ancestor(top, &60, &80), Some(&70));
+    }
+
+    #[test]
+    fn lowest_common_ancestor_can_be_one_of_the_two() {
+        let root = tree(&[50, 30, 70, 20, 40, 60, 80]);
+        let top = root.as_ref().unwrap();
+
+        assert_eq!(BstNode::lowest_common_ancestor(top, &70, &60), Some(&70));
+        assert_eq!(BstNode::lowest_common_ancestor(top, &50, &20), Some(&50));
+        assert_eq!(BstNode::lowest_common_ancestor(top, &40, &40), Some(&40));
+    }
+
+    #[test]
+    fn lowest_common_ancestor_needs_both_values_in_the_tree() {
+        let root = tree(&[50, 30, 70, 20, 40, 60, 80]);
+        let top = root.as_ref().unwrap();
+
+        assert_eq!(BstNode::lowest_common_ancestor(top, &20, &45), None);
+        assert_eq!(BstNode::lowest_common_ancestor(top, &45, &20), None);
+        assert_eq!(BstNode::lowest_common_ancestor(top, &5, &10), None);
+        assert_eq!(BstNode::lowest_common_ancestor(top, &90, &95), None);
+    }
+
+    #[test]
+    fn range_includes_both_bounds() {
+        let root = tree(&[50, 30, 70, 20, 40, 60, 80]);
+        let top = root.as_ref().unwrap();
+
+        assert_eq!(top.range(&0, &100), [&20, &30, &40, &50, &60, &70, &80]);
+        assert_eq!(top.range(&35, &65), [&40, &50, &60]);
+        assert_eq!(top.range(&30, &60), [&30, &40, &50, &60]);
+        assert_eq!(top.range(&50, &50), [&50]);
+    }
+
+    #[test]
+    fn range_can_be_empty() {
+        let root = tree(&[50, 30, 70, 20, 40, 60, 80]);
+        let top = root.as_ref().unwrap();
+
+        assert!(top.range(&41, &49).is_empty());
+        assert!(top.range(&81, &100).is_empty());
+        assert!(top.range(&0, &19).is_empty());
+        assert!(top.range(&60, &40).is_empty());
+    }
+
+    #[test]
+    fn range_agrees_with_filtering_the_in_order_walk() {
+        let items: Vec<i32> = (0..200).map(|i| i * 7919 % 200).collect();
+        let root = tree(&items);
+        let top = root.as_ref().unwrap();
+
+        for (lo, hi) in [(0, 199), (13, 57), (100, 100), (150, 400), (-5, 3)] {
+            let expected: Vec<&i32> = BstNode::in_order(&root)
+                .into_iter()
+                .filter(|&&x| lo <= x && x <= hi)
+                .collect();
+            assert_eq!(top.range(&lo, &hi), expected);
+        }
+    }
+
+    #[test]
+    fn pretty_print_puts_the_right_subtree_on_top() {
+        let root = tree(&[50, 30, 70, 20, 40, 60, 80]);
+        let expected = [
+            "        80",
+            "    70",
+            "        60",
+            "50",
+            "        40",
+            "    30",
+            "        20",
+        ];
+        assert_eq!(BstNode::pretty_print(&root), expected.join("\n") + "\n");
+
+        assert_eq!(
+            BstNode::pretty_print(&tree(&[2, 1, 3, 4])),
+            "        4\n    3\n2\n    1\n"
+        );
+    }
+
+    #[test]
+    fn pretty_print_indents_one_level_per_depth() {
+        assert_eq!(
+            BstNode::pretty_print(&tree(&[3, 2, 1])),
+            "3\n    2\n        1\n"
+        );
+        assert_eq!(BstNode::pretty_print(&tree(&[7])), "7\n");
+        assert_eq!(BstNode::<i32>::pretty_print(&None), "");
+    }
+
+    #[test]
+    fn link_reversal_in_order_matches_the_recursive_walk() {
+        for items in [
+            &[50, 30, 70, 20, 40, 60, 80][..],
+            &[15, 3, 42, 8, 23, 4, 16, 99, 1],
+            &[1, 2, 3],
+            &[3, 2, 1],
+            &[7],
+        ] {
+            let mut root = tree(items);
+            assert_eq!(BstNode::link_reversal_in_order(&mut root), sorted(&root));
+        }
+        assert!(BstNode::<i32>::link_reversal_in_order(&mut None).is_empty());
+    }
+
+    #[test]
+    fn link_reversal_in_order_leaves_the_tree_unchanged() {
+        let items: Vec<i32> = (0..200).map(|i| i * 7919 % 200).collect();
+        for items in [
+            &items[..],
+            &[50, 30, 70, 20, 40, 60, 80],
+            &[1, 2, 3, 4],
+            &[4, 3, 2, 1],
+        ] {
+            let mut root = tree(items);
+            let shape = BstNode::serialize(&root);
+
+            BstNode::link_reversal_in_order(&mut root);
+            assert_eq!(BstNode::serialize(&root), shape);
+            assert!(root == tree(items));
+        }
+    }
+
+    fn node(
+        data: i32,
+        left: Option<Box<BstNode<i32>>>,
+        right: Option<Box<BstNode<i32>>>,
+    ) -> Option<Box<BstNode<i32>>> {
+        Some(Box::new(BstNode { data, left, right }))
+    }
+
+    #[test]
+    fn link_reversal_in_order_doesnt_rely_on_the_search_order() {
+        let leaf = |data| node(data, None, None);
+        // Built by hand, deserialize rejects all of these
+        for (mut root, expected, shape) in [
+            (node(1, leaf(1), None), vec![1, 1], "1,1,#,#,#"),
+            (node(1, leaf(2), None), vec![2, 1], "1,2,#,#,#"),
+            (node(5, leaf(3), leaf(3)), vec![3, 5, 3], "5,3,#,#,3,#,#"),
+            (node(2, None, leaf(2)), vec![2, 2], "2,#,2,#,#"),
+            (
+                node(
+                    4,
+                    node(9, leaf(9), node(1, None, leaf(7))),
+                    node(4, leaf(4), None),
+                ),
+                vec![9, 9, 1, 7, 4, 4, 4],
+                "4,9,9,#,#,1,#,7,#,#,4,4,#,#,#",
+            ),
+        ] {
+            let recursive: Vec<i32> = BstNode::in_order(&root).into_iter().copied().collect();
+            assert_eq!(recursive, expected);
+
+            assert_eq!(BstNode::link_reversal_in_order(&mut root), expected);
+            assert_eq!(BstNode::serialize(&root), shape);
+        }
+    }
+}
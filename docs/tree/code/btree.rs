@@ -0,0 +1,302 @@
+// A node holds its keys in sorted order, with one more child than keys when it isn't
+// a leaf: children[i] holds everything between keys[i - 1] and keys[i].
+#[allow(dead_code)]
+struct BTreeNode {
+    keys: Vec<i32>,
+    children: Vec<BTreeNode>,
+}
+
+#[allow(dead_code)]
+impl BTreeNode {
+    fn new() -> Self {
+        BTreeNode {
+            keys: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    // Splits the full child children[i] (2t - 1 keys) into two nodes of t - 1 keys each,
+    // and moves the median key up into this node between them.
+    fn split_child(&mut self, i: usize, t: usize) {
+        let full = &mut self.children[i];
+
+        let right = BTreeNode {
+            keys: full.keys.split_off(t),
+            children: if full.is_leaf() {
+                Vec::new()
+            } else {
+                full.children.split_off(t)
+            },
+        };
+        let median = full.keys.pop().unwrap();
+
+        self.keys.insert(i, median);
+        self.children.insert(i + 1, right);
+    }
+
+    // Any full child is split before going into it, so there's always room for the
+    // median that a split pushes up, and the key lands in a leaf that isn't full
+    fn insert_non_full(&mut self, key: i32, t: usize) {
+        let mut i = self.keys.partition_point(|&k| k < key);
+
+        if self.is_leaf() {
+            self.keys.insert(i, key);
+            return;
+        }
+
+        if self.children[i].keys.len() == 2 * t - 1 {
+            self.split_child(i, t);
+
+            // The median that came up might belong before the key
+            if key > self.keys[i] {
+                i += 1;
+            }
+        }
+
+        self.children[i].insert_non_full(key, t);
+    }
+
+    // Every non-root node has between t - 1 and 2t - 1 keys, so it's at least half
+    // full, and a node with k keys has k + 1 children
+    fn fill_holds(&self, t: usize, is_root: bool) -> bool {
+        let n = self.keys.len();
+        let enough = is_root || n >= t - 1;
+        let shape = self.is_leaf() || self.children.len() == n + 1;
+        let below = self.children.iter().all(|child| child.fill_holds(t, false));
+
+        enough && n < 2 * t && shape && below
+    }
+
+    fn keys_into(&self, result: &mut Vec<i32>) {
+        for (i, &key) in self.keys.iter().enumerate() {
+            if let Some(child) = self.children.get(i) {
+                child.keys_into(result);
+            }
+            result.push(key);
+        }
+
+        if let Some(last) = self.children.get(self.keys.len()) {
+            last.keys_into(result);
+        }
+    }
+}
+
+// t is the minimum degree: every node but the root has at least t children (t - 1
+// keys) and at most 2t children (2t - 1 keys). A wide node means a shallow tree, which
+// is why databases use B-trees for indexes: one node fits a disk page, and finding a
+// key reads only as many pages as the tree is tall.
+#[allow(dead_code)]
+struct BTree {
+    root: BTreeNode,
+    t: usize,
+}
+
+#[allow(dead_code)]
+impl BTree {
+    fn new(t: usize) -> Self {
+        assert!(t >= 2, "a B-tree needs a minimum degree of at least 2");
+
+        BTree {
+            root: BTreeNode::new(),
+            t,
+        }
+    }
+
+    fn search(&self, key: i32) -> bool {
+        let mut node = &self.root;
+
+        loop {
+            match node.keys.binary_search(&key) {
+                Ok(_) => return true,
+                Err(_) if node.is_leaf() => return false,
+                Err(i) => node = &node.children[i],
+            }
+        }
+    }
+
+    // The tree only grows taller when the root splits, which adds a level above every
+    // leaf at once. That's why all leaves are always at the same depth.
+    fn insert(&mut self, key: i32) {
+        if self.search(key) {
+            return; // Duplicates are ignored
+        }
+
+        if self.root.keys.len() == 2 * self.t - 1 {
+            let old_root = std::mem::replace(&mut self.root, BTreeNode::new());
+            self.root.children.push(old_root);
+            self.root.split_child(0, self.t);
+        }
+
+        self.root.insert_non_full(key, self.t);
+    }
+
+    // Counted in levels, so a tree that's only a root has height 1
+    fn height(&self) -> usize {
+        let mut node = &self.root;
+        let mut height = 1;
+
+        while let Some(first) = node.children.first() {
+            node = first;
+            height += 1;
+        }
+
+        height
+    }
+
+    fn fill_holds(&self) -> bool {
+        self.root.fill_holds(self.t, true)
+    }
+
+    fn keys(&self) -> Vec<i32> {
+        let mut result = Vec::new();
+        self.root.keys_into(&mut result);
+        result
+    }
+}
+
+fn main() {
+    // t = 2 is a 2-3-4 tree: every node has 1 to 3 keys
+    let mut small = BTree::new(2);
+    for key in [10, 20, 30, 40, 50, 60, 70, 80, 90] {
+        small.insert(key);
+    }
+
+    //                [40]
+    //          /              \
+    //       [20]              [60]
+    //      /    \           /      \
+    //   [10]    [30]     [50]    [70, 80, 90]
+    println!("{:?}", small.root.keys); // [40]
+    let children: Vec<&Vec<i32>> = small.root.children.iter().map(|c| &c.keys).collect();
+    println!("{:?}", children); // [[20], [60]]
+    println!("Height: {}", small.height()); // Height: 3
+    println!("{:?}", small.keys()); // [10, 20, 30, 40, 50, 60, 70, 80, 90]
+    println!("Contains 60: {}", small.search(60)); // Contains 60: true
+    println!("Contains 65: {}", small.search(65)); // Contains 65: false
+
+    // 1000 keys in a scrambled order: 7919 and 1000 share no factors, so i * 7919 % 1000
+    // visits every value in 0..1000 exactly once
+    for t in [2, 3, 10] {
+        let mut tree = BTree::new(t);
+        for i in 0..1000 {
+            tree.insert(i * 7919 % 1000);
+        }
+
+        assert!(tree.fill_holds());
+        assert!((0..1000).all(|key| tree.search(key)));
+        assert!(!tree.search(-1) && !tree.search(1000));
+        assert_eq!(tree.keys(), (0..1000).collect::<Vec<_>>());
+
+        println!("t = {}: height {}", t, tree.height());
+    }
+    // t = 2: height 8
+    // t = 3: height 5
+    // t = 10: height 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0..n in a scrambled order, every value exactly once
+    fn scrambled(n: i32) -> impl Iterator<Item = i32> {
+        (0..n).map(move |i| i * 7919 % n)
+    }
+
+    #[test]
+    fn insert_splits_the_root_into_a_new_level() {
+        let mut tree = BTree::new(2);
+        for key in [10, 20, 30] {
+            tree.insert(key);
+        }
+        assert_eq!(tree.root.keys, vec![10, 20, 30]);
+        assert_eq!(tree.height(), 1);
+
+        // The root is full, so the next insert splits it around 20
+        tree.insert(40);
+        assert_eq!(tree.root.keys, vec![20]);
+        let children: Vec<&Vec<i32>> = tree.root.children.iter().map(|c| &c.keys).collect();
+        assert_eq!(children, [&vec![10], &vec![30, 40]]);
+        assert_eq!(tree.height(), 2);
+        assert!(tree.fill_holds());
+    }
+
+    #[test]
+    fn keys_stay_sorted_and_nodes_half_full() {
+        for t in [2, 3, 4, 10] {
+            let mut tree = BTree::new(t);
+            for key in scrambled(1000) {
+                tree.insert(key);
+                assert!(tree.fill_holds(), "t = {}", t);
+            }
+
+            assert_eq!(tree.keys(), (0..1000).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn all_leaves_are_at_the_same_depth() {
+        fn leaf_depths(node: &BTreeNode, depth: usize, depths: &mut Vec<usize>) {
+            if node.is_leaf() {
+                depths.push(depth);
+            }
+            for child in &node.children {
+                leaf_depths(child, depth + 1, depths);
+            }
+        }
+
+        for t in [2, 3, 10] {
+            let mut tree = BTree::new(t);
+            scrambled(1000).for_each(|key| tree.insert(key));
+
+            let mut depths = Vec::new();
+            leaf_depths(&tree.root, 1, &mut depths);
+            assert!(depths.iter().all(|&d| d == tree.height()));
+        }
+    }
+
+    #[test]
+    fn search_finds_only_inserted_keys() {
+        let mut tree = BTree::new(3);
+        assert!(!tree.search(0));
+
+        for key in (0..200).map(|k| k * 2) {
+            tree.insert(key);
+        }
+        assert!((0..200).all(|k| tree.search(k * 2)));
+        assert!((0..200).all(|k| !tree.search(k * 2 + 1)));
+        assert!(!tree.search(-2));
+    }
+
+    #[test]
+    fn duplicates_are_ignored() {
+        let mut tree = BTree::new(2);
+        for key in [5, 3, 5, 8, 3, 5] {
+            tree.insert(key);
+        }
+        assert_eq!(tree.keys(), vec![3, 5, 8]);
+    }
+
+    #[test]
+    fn a_wider_node_makes_a_shorter_tree() {
+        let heights: Vec<usize> = [2, 3, 10]
+            .into_iter()
+            .map(|t| {
+                let mut tree = BTree::new(t);
+                scrambled(1000).for_each(|key| tree.insert(key));
+                tree.height()
+            })
+            .collect();
+        assert_eq!(heights, vec![8, 5, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a B-tree needs a minimum degree of at least 2")]
+    fn a_minimum_degree_below_two_is_rejected() {
+        BTree::new(1);
+    }
+}
@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Color {
+    Red,
+    Black,
+}
+
+// Left-leaning red-black tree: a red node is glued to its parent, and red links only
+// ever lean left. That rules out the mirrored cases of the usual fixup, so insert
+// needs just three rules, applied on the way back up.
+#[allow(dead_code)]
+struct RbNode {
+    data: i32,
+    color: Color,
+    left: Option<Box<RbNode>>,
+    right: Option<Box<RbNode>>,
+}
+
+#[allow(dead_code)]
+impl RbNode {
+    // New nodes are red, so adding one never changes the number of black nodes on a path
+    fn new(data: i32) -> Box<RbNode> {
+        Box::new(RbNode {
+            data,
+            color: Color::Red,
+            left: None,
+            right: None,
+        })
+    }
+
+    // Missing children count as black
+    fn is_red(root: &Option<Box<RbNode>>) -> bool {
+        root.as_ref().is_some_and(|node| node.color == Color::Red)
+    }
+
+    //     x                  y
+    //    / \                / \
+    //   A   y     ->       x   C
+    //      / \            / \
+    //     B   C          A   B
+    // y takes over x's color and x turns red, so the red link now leans left
+    fn rotate_left(mut x: Box<RbNode>) -> Box<RbNode> {
+        let mut y = x.right.take().unwrap();
+
+        x.right = y.left.take();
+        y.color = x.color;
+        x.color = Color::Red;
+        y.left = Some(x);
+
+        y
+    }
+
+    //       y              x
+    //      / \            / \
+    //     x   C   ->     A   y
+    //    / \                / \
+    //   A   B              B   C
+    fn rotate_right(mut y: Box<RbNode>) -> Box<RbNode> {
+        let mut x = y.left.take().unwrap();
+
+        y.left = x.right.take();
+        x.color = y.color;
+        y.color = Color::Red;
+        x.right = Some(y);
+
+        x
+    }
+
+    // Both children red: push the red up to the parent instead
+    fn flip_colors(node: &mut RbNode) {
+        node.color = Color::Red;
+        node.left.as_mut().unwrap().color = Color::Black;
+        node.right.as_mut().unwrap().color = Color::Black;
+    }
+
+    fn insert(root: Option<Box<RbNode>>, data: i32) -> Option<Box<RbNode>> {
+        let mut root = RbNode::insert_into(root, data);
+
+        // A red root has no parent to be glued to, so it can always be made black
+        root.color = Color::Black;
+        Some(root)
+    }
+
+    fn insert_into(root: Option<Box<RbNode>>, data: i32) -> Box<RbNode> {
+        let mut node = match root {
+            None => return RbNode::new(data),
+            Some(node) => node,
+        };
+
+        match data.cmp(&node.data) {
+            Ordering::Less => node.left = Some(RbNode::insert_into(node.left.take(), data)),
+            Ordering::Greater => node.right = Some(RbNode::insert_into(node.right.take(), data)),
+            Ordering::Equal => return node, // Duplicates are ignored
+        }
+
+        // A red link leaning right gets rotated to lean left
+        if RbNode::is_red(&node.right) && !RbNode::is_red(&node.left) {
+            node = RbNode::rotate_left(node);
+        }
+        // Two reds in a row on the left: rotate so they become siblings
+        if RbNode::is_red(&node.left) && RbNode::is_red(&node.left.as_ref().unwrap().left) {
+            node = RbNode::rotate_right(node);
+        }
+        // Two red siblings: recolor and pass the red up
+        if RbNode::is_red(&node.left) && RbNode::is_red(&node.right) {
+            RbNode::flip_colors(&mut node);
+        }
+
+        node
+    }
+
+    fn contains(root: &Option<Box<RbNode>>, data: i32) -> bool {
+        let mut current = root;
+
+        while let Some(node) = current {
+            current = match data.cmp(&node.data) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+
+        false
+    }
+
+    fn in_order(root: &Option<Box<RbNode>>) -> Vec<i32> {
+        let mut result = Vec::new();
+        RbNode::in_order_into(root, &mut result);
+        result
+    }
+
+    fn in_order_into(root: &Option<Box<RbNode>>, result: &mut Vec<i32>) {
+        if let Some(node) = root {
+            RbNode::in_order_into(&node.left, result);
+            result.push(node.data);
+            RbNode::in_order_into(&node.right, result);
+        }
+    }
+
+    fn height(root: &Option<Box<RbNode>>) -> usize {
+        root.as_ref().map_or(0, |node| {
+            1 + RbNode::height(&node.left).max(RbNode::height(&node.right))
+        })
+    }
+
+    // The number of black nodes on every path down to a missing child, or None if the
+    // paths disagree or a red node has a red child. Holding both is what keeps the
+    // height within 2 * log2(n + 1): no path can be more than twice as long as another.
+    fn black_height(root: &Option<Box<RbNode>>) -> Option<usize> {
+        let Some(node) = root else {
+            return Some(0);
+        };
+
+        let red = node.color == Color::Red;
+        if red && (RbNode::is_red(&node.left) || RbNode::is_red(&node.right)) {
+            return None;
+        }
+
+        let left = RbNode::black_height(&node.left)?;
+        let right = RbNode::black_height(&node.right)?;
+        if left != right {
+            return None;
+        }
+
+        Some(left + if red { 0 } else { 1 })
+    }
+}
+
+fn main() {
+    let mut root = None;
+
+    for data in [10, 20, 30] {
+        root = RbNode::insert(root, data); // 20 leans right and 30 makes two red siblings
+    }
+    //     20
+    //    /  \
+    //   10  30
+    let top = root.as_ref().unwrap();
+    println!("Root: {}", top.data); // Root: 20
+    assert!(top.color == Color::Black);
+    println!("{:?}", RbNode::black_height(&root)); // Some(2)
+
+    for data in [25, 28, 5, 1] {
+        root = RbNode::insert(root, data);
+    }
+    println!("{:?}", RbNode::in_order(&root)); // [1, 5, 10, 20, 25, 28, 30]
+    println!("Contains 28: {}", RbNode::contains(&root, 28)); // Contains 28: true
+    println!("Contains 15: {}", RbNode::contains(&root, 15)); // Contains 15: false
+
+    // Ascending inserts are the worst case for a plain BST (height 1000)
+    let mut ascending = None;
+    for data in 1..=1000 {
+        ascending = RbNode::insert(ascending, data);
+    }
+
+    println!("{:?}", RbNode::black_height(&ascending)); // Some(9)
+
+    let height = RbNode::height(&ascending);
+    let bound = 2.0 * (1000.0_f64 + 1.0).log2();
+    println!("Height: {}", height); // Height: 10
+    assert!((height as f64) <= bound);
+
+    assert_eq!(RbNode::in_order(&ascending), (1..=1000).collect::<Vec<_>>());
+    assert!((1..=1000).all(|x| RbNode::contains(&ascending, x)));
+
+    // Inserting an existing value changes nothing
+    ascending = RbNode::insert(ascending, 500);
+    println!("Size: {}", RbNode::in_order(&ascending).len()); // Size: 1000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(items: impl IntoIterator<Item = i32>) -> Option<Box<RbNode>> {
+        items.into_iter().fold(None, RbNode::insert)
+    }
+
+    // Red links only ever lean left
+    fn no_red_right_links(root: &Option<Box<RbNode>>) -> bool {
+        root.as_ref().is_none_or(|node| {
+            !RbNode::is_red(&node.right)
+                && no_red_right_links(&node.left)
+                && no_red_right_links(&node.right)
+        })
+    }
+
+    fn assert_valid(root: &Option<Box<RbNode>>) {
+        assert!(!RbNode::is_red(root));
+        assert!(RbNode::black_height(root).is_some());
+        assert!(no_red_right_links(root));
+    }
+
+    #[test]
+    fn every_insert_order_keeps_the_invariants() {
+        let scrambled: Vec<i32> = (0..500).map(|i| i * 7919 % 500).collect();
+        let orders = [
+            (1..=500).collect::<Vec<_>>(),
+            (1..=500).rev().collect(),
+            scrambled,
+            vec![10, 20, 30, 25, 28, 5, 1],
+        ];
+
+        for order in orders {
+            let mut root = None;
+            for &data in &order {
+                root = RbNode::insert(root, data);
+                assert_valid(&root);
+            }
+
+            let mut expected = order.clone();
+            expected.sort();
+            assert_eq!(RbNode::in_order(&root), expected);
+        }
+    }
+
+    #[test]
+    fn height_stays_within_twice_log_n() {
+        for n in [1, 10, 100, 1000, 10_000] {
+            let root = tree(1..=n);
+            let bound = 2.0 * (n as f64 + 1.0).log2();
+            assert!(RbNode::height(&root) as f64 <= bound, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn black_height_counts_black_nodes_per_path() {
+        assert_eq!(RbNode::black_height(&None), Some(0));
+        assert_eq!(RbNode::black_height(&tree([1])), Some(1));
+        assert_eq!(RbNode::black_height(&tree([10, 20, 30])), Some(2));
+        assert_eq!(RbNode::black_height(&tree(1..=1000)), Some(9));
+    }
+
+    #[test]
+    fn black_height_rejects_broken_trees() {
+        // A red node with a red child
+        let mut reds = RbNode::new(2);
+        reds.left = Some(RbNode::new(1));
+        assert_eq!(RbNode::black_height(&Some(reds)), None);
+
+        // One black node on the left path, none on the right
+        let mut lopsided = RbNode::new(2);
+        lopsided.color = Color::Black;
+        let mut left = RbNode::new(1);
+        left.color = Color::Black;
+        lopsided.left = Some(left);
+        assert_eq!(RbNode::black_height(&Some(lopsided)), None);
+    }
+
+    #[test]
+    fn contains_and_duplicates() {
+        let mut root = tree([10, 20, 30, 25, 28, 5, 1]);
+        for data in [1, 5, 10, 20, 25, 28, 30] {
+            assert!(RbNode::contains(&root, data));
+        }
+        assert!(!RbNode::contains(&root, 15));
+        assert!(!RbNode::contains(&None, 15));
+
+        root = RbNode::insert(root, 20);
+        assert_eq!(RbNode::in_order(&root), vec![1, 5, 10, 20, 25, 28, 30]);
+        assert_valid(&root);
+    }
+}
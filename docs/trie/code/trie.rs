@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+#[allow(dead_code)]
+struct TrieNode {
+    children: HashMap<char, Box<TrieNode>>,
+    // Marks that a whole word ends here, not just that some word passes through
+    is_end: bool,
+}
+
+impl TrieNode {
+    fn new() -> Self {
+        TrieNode {
+            children: HashMap::new(),
+            is_end: false,
+        }
+    }
+}
+
+#[allow(dead_code)]
+struct Trie {
+    root: TrieNode,
+}
+
+#[allow(dead_code)]
+impl Trie {
+    fn new() -> Self {
+        Trie {
+            root: TrieNode::new(),
+        }
+    }
+
+    fn insert(&mut self, word: &str) {
+        let mut current = &mut self.root;
+
+        for c in word.chars() {
+            current = current
+                .children
+                .entry(c)
+                .or_insert_with(|| Box::new(TrieNode::new()));
+        }
+
+        current.is_end = true;
+    }
+
+    // Follows `prefix` one character at a time, returning the node it ends on
+    fn find_node(&self, prefix: &str) -> Option<&TrieNode> {
+        let mut current = &self.root;
+
+        for c in prefix.chars() {
+            current = current.children.get(&c)?;
+        }
+
+        Some(current)
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.find_node(word).is_some_and(|node| node.is_end)
+    }
+
+    fn starts_with(&self, prefix: &str) -> bool {
+        self.find_node(prefix).is_some()
+    }
+
+    fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = Vec::new();
+
+        if let Some(node) = self.find_node(prefix) {
+            let mut current = prefix.to_string();
+            Trie::collect_words(node, &mut current, &mut words);
+        }
+
+        words
+    }
+
+    // DFS that visits children in character order, so words come out already sorted
+    fn collect_words(node: &TrieNode, current: &mut String, words: &mut Vec<String>) {
+        if node.is_end {
+            words.push(current.clone());
+        }
+
+        let mut keys: Vec<&char> = node.children.keys().collect();
+        keys.sort();
+
+        for c in keys {
+            current.push(*c);
+            Trie::collect_words(&node.children[c], current, words);
+            current.pop();
+        }
+    }
+}
+
+fn main() {
+    let mut trie = Trie::new();
+
+    trie.insert("app");
+    trie.insert("apple");
+    trie.insert("bat");
+
+    println!("Contains app: {}", trie.contains("app")); // Contains app: true
+    println!("Contains apple: {}", trie.contains("apple")); // Contains apple: true
+    println!("Contains appl: {}", trie.contains("appl")); // Contains appl: false
+    println!("Starts with appl: {}", trie.starts_with("appl")); // Starts with appl: true
+    println!("Starts with c: {}", trie.starts_with("c")); // Starts with c: false
+
+    // Every word starts with the empty prefix, but "" isn't a word until it's inserted
+    println!("Contains \"\": {}", trie.contains("")); // Contains "": false
+    println!("Starts with \"\": {}", trie.starts_with("")); // Starts with "": true
+
+    trie.insert("");
+    println!("Contains \"\": {}", trie.contains("")); // Contains "": true
+
+    trie.insert("application");
+    trie.insert("apply");
+    trie.insert("banana");
+
+    println!("{:?}", trie.words_with_prefix("app")); // ["app", "apple", "application", "apply"]
+    println!("{:?}", trie.words_with_prefix("ba")); // ["banana", "bat"]
+    println!("{:?}", trie.words_with_prefix("ban")); // ["banana"]
+    println!("{:?}", trie.words_with_prefix("cat")); // []
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trie(words: &[&str]) -> Trie {
+        let mut trie = Trie::new();
+        for word in words {
+            trie.insert(word);
+        }
+        trie
+    }
+
+    #[test]
+    fn contains_needs_a_whole_word() {
+        let trie = trie(&["app", "apple", "bat"]);
+
+        assert!(trie.contains("app"));
+        assert!(trie.contains("apple"));
+        assert!(!trie.contains("appl"));
+        assert!(!trie.contains("apples"));
+        assert!(!trie.contains(""));
+    }
+
+    #[test]
+    fn starts_with_accepts_any_prefix() {
+        let trie = trie(&["app", "apple", "bat"]);
+
+        assert!(trie.starts_with("appl"));
+        assert!(trie.starts_with("apple"));
+        assert!(trie.starts_with(""));
+        assert!(!trie.starts_with("c"));
+        assert!(!trie.starts_with("apples"));
+    }
+
+    #[test]
+    fn words_with_prefix_returns_sorted_matches() {
+        let trie = trie(&["apply", "app", "banana", "application", "apple", "bat"]);
+
+        assert_eq!(
+            trie.words_with_prefix("app"),
+            ["app", "apple", "application", "apply"]
+        );
+        assert_eq!(trie.words_with_prefix("ba"), ["banana", "bat"]);
+        assert_eq!(trie.words_with_prefix("apple"), ["apple"]);
+        assert!(trie.words_with_prefix("cat").is_empty());
+    }
+
+    #[test]
+    fn empty_prefix_lists_every_word() {
+        let trie = trie(&["b", "a", "ab"]);
+        assert_eq!(trie.words_with_prefix(""), ["a", "ab", "b"]);
+    }
+}
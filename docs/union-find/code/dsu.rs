@@ -0,0 +1,234 @@
+// Each set is a tree, identified by its root. parent[x] == x means x is a root.
+// rank[x] is an upper bound on the height of the tree rooted at x.
+#[allow(dead_code)]
+struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+}
+
+#[allow(dead_code)]
+impl DisjointSet {
+    fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+        }
+    }
+
+    // Path compression: point every node on the way straight at the root
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+
+        self.parent[x]
+    }
+
+    // Union by rank: hang the shorter tree under the taller one.
+    // Returns false if a and b were already in the same set.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+
+        if root_a == root_b {
+            return false;
+        }
+
+        if self.rank[root_a] < self.rank[root_b] {
+            self.parent[root_a] = root_b;
+        } else if self.rank[root_a] > self.rank[root_b] {
+            self.parent[root_b] = root_a;
+        } else {
+            self.parent[root_b] = root_a;
+            self.rank[root_a] += 1;
+        }
+
+        true
+    }
+
+    fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // Number of parent links from x to its root, without compressing anything
+    fn depth(&self, mut x: usize) -> usize {
+        let mut depth = 0;
+
+        while self.parent[x] != x {
+            x = self.parent[x];
+            depth += 1;
+        }
+
+        depth
+    }
+}
+
+// Kruskal's MST: take edges from lightest to heaviest, skipping any edge whose
+// endpoints are already connected (it would close a cycle).
+fn kruskal(edges: &[(usize, usize, u32)], n: usize) -> Vec<(usize, usize, u32)> {
+    let mut sorted = edges.to_vec();
+    sorted.sort_by_key(|&(_, _, weight)| weight);
+
+    let mut dsu = DisjointSet::new(n);
+    let mut mst = Vec::new();
+
+    for (u, v, weight) in sorted {
+        if dsu.union(u, v) {
+            mst.push((u, v, weight));
+        }
+
+        // A spanning tree on n vertices has exactly n - 1 edges
+        if mst.len() + 1 == n {
+            break;
+        }
+    }
+
+    mst
+}
+
+fn main() {
+    let mut dsu = DisjointSet::new(8);
+
+    dsu.union(0, 1); // {0, 1} {2} {3} {4} {5} {6} {7}
+    dsu.union(2, 3); // {0, 1} {2, 3} {4} {5} {6} {7}
+    dsu.union(1, 3); // {0, 1, 2, 3} {4} {5} {6} {7}
+
+    println!("0 ~ 3: {}", dsu.connected(0, 3)); // 0 ~ 3: true
+    println!("0 ~ 4: {}", dsu.connected(0, 4)); // 0 ~ 4: false
+    println!("Merged again: {}", dsu.union(0, 2)); // Merged again: false
+
+    // Merging a chain one element at a time would make a tall tree without union by rank
+    let n = 1000;
+    let mut chain = DisjointSet::new(n);
+    for i in 1..n {
+        chain.union(i - 1, i);
+    }
+
+    let max_depth = (0..n).map(|x| chain.depth(x)).max().unwrap();
+    println!("Max depth: {}", max_depth); // Max depth: 1
+    println!("0 ~ 999: {}", chain.connected(0, n - 1)); // 0 ~ 999: true
+
+    //      2       3
+    //   0 ---- 1 ---- 2
+    //   |    / |     /
+    // 6 |  8/  |5   /7
+    //   | /    |   /
+    //   3 ---- 4 -
+    //      9
+    let edges = [
+        (0, 1, 2),
+        (0, 3, 6),
+        (1, 2, 3),
+        (1, 3, 8),
+        (1, 4, 5),
+        (2, 4, 7),
+        (3, 4, 9),
+    ];
+
+    let mst = kruskal(&edges, 5);
+    let total: u32 = mst.iter().map(|&(_, _, weight)| weight).sum();
+    println!("{:?}", mst); // [(0, 1, 2), (1, 2, 3), (1, 4, 5), (0, 3, 6)]
+    println!("Total weight: {}", total); // Total weight: 16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_merges_sets_once() {
+        let mut dsu = DisjointSet::new(6);
+
+        assert!(dsu.union(0, 1));
+        assert!(dsu.union(2, 3));
+        assert!(dsu.union(1, 3));
+        assert!(!dsu.union(0, 2));
+
+        assert!(dsu.connected(0, 3));
+        assert!(!dsu.connected(0, 4));
+        assert!(!dsu.connected(4, 5));
+        assert!(dsu.connected(5, 5));
+    }
+
+    #[test]
+    fn find_compresses_the_path_it_walks() {
+        let mut dsu = DisjointSet::new(4);
+        // Build 3 -> 2 -> 1 -> 0 by hand, which union by rank would never make
+        dsu.parent = vec![0, 0, 1, 2];
+
+        assert_eq!(dsu.depth(3), 3);
+        assert_eq!(dsu.find(3), 0);
+        assert!((0..4).all(|x| dsu.depth(x) <= 1));
+    }
+
+    fn total(mst: &[(usize, usize, u32)]) -> u32 {
+        mst.iter().map(|&(_, _, weight)| weight).sum()
+    }
+
+    #[test]
+    fn kruskal_picks_the_lightest_spanning_tree() {
+        let edges = [
+            (0, 1, 2),
+            (0, 3, 6),
+            (1, 2, 3),
+            (1, 3, 8),
+            (1, 4, 5),
+            (2, 4, 7),
+            (3, 4, 9),
+        ];
+        let mst = kruskal(&edges, 5);
+
+        assert_eq!(mst, vec![(0, 1, 2), (1, 2, 3), (1, 4, 5), (0, 3, 6)]);
+        assert_eq!(total(&mst), 16);
+    }
+
+    #[test]
+    fn kruskal_on_a_disconnected_graph_gives_a_forest() {
+        // {0, 1, 2} and {3, 4} never meet, so one edge short of a spanning tree
+        let edges = [(0, 1, 1), (1, 2, 1), (0, 2, 5), (3, 4, 2)];
+        let mst = kruskal(&edges, 5);
+
+        assert_eq!(mst.len(), 3);
+        assert_eq!(total(&mst), 4);
+    }
+
+    fn max_depth(dsu: &DisjointSet) -> usize {
+        (0..dsu.parent.len())
+            .map(|x| dsu.depth(x))
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn merging_a_chain_keeps_the_trees_shallow() {
+        for n in [2, 10, 1000, 4096] {
+            let mut chain = DisjointSet::new(n);
+            for i in 1..n {
+                chain.union(i - 1, i);
+            }
+
+            assert!(max_depth(&chain) <= n.ilog2() as usize, "n = {}", n);
+            assert!(chain.connected(0, n - 1));
+        }
+    }
+
+    #[test]
+    fn union_by_rank_alone_caps_the_height_at_log2_n() {
+        // Merging equal-sized roots: every union call is on two roots, so find never
+        // has a path to compress and the height comes from rank alone. This is the
+        // tallest tree union by rank can build, one level per doubling.
+        let n = 1024;
+        let mut dsu = DisjointSet::new(n);
+        let mut size = 1;
+        while size < n {
+            for i in (0..n).step_by(2 * size) {
+                dsu.union(i, i + size);
+            }
+            size *= 2;
+        }
+
+        assert_eq!(max_depth(&dsu), 10);
+        let root = dsu.find(0);
+        assert_eq!(dsu.rank[root], 10);
+    }
+}